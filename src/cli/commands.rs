@@ -1,5 +1,8 @@
 use clap::{Parser, Subcommand};
 
+use crate::domain::providers::vercel::VERCEL_TARGETS;
+use crate::domain::{validate_name, ExportFormat, HandSort, MergeStrategy, TotpAlgorithm};
+
 #[derive(Parser)]
 #[command(name = "hc")]
 #[command(about = "Secure password manager CLI", long_about = None)]
@@ -7,6 +10,44 @@ pub struct Cli {
     #[arg(long, global = true, help = "Deck name to use")]
     pub deck: Option<String>,
 
+    #[arg(
+        short = 'y',
+        long,
+        global = true,
+        help = "Assume yes for confirmation prompts (non-interactive)"
+    )]
+    pub yes: bool,
+
+    #[arg(
+        short = 'q',
+        long,
+        global = true,
+        help = "Suppress non-essential status output"
+    )]
+    pub quiet: bool,
+
+    #[arg(
+        long,
+        global = true,
+        help = "Disable colored output (also honours NO_COLOR)"
+    )]
+    pub no_color: bool,
+
+    #[arg(
+        long,
+        global = true,
+        help = "Show what hand, card, TOTP and import changes would be saved without writing the deck"
+    )]
+    pub dry_run: bool,
+
+    #[arg(
+        short = 'v',
+        long,
+        global = true,
+        help = "Log debug details (paths, HTTP statuses, ssh-add calls) to stderr; never secrets"
+    )]
+    pub verbose: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -16,6 +57,9 @@ pub enum Commands {
     #[command(about = "Initialize a new deck")]
     Init,
 
+    #[command(about = "Show which deck commands will act on")]
+    Current,
+
     #[command(about = "Manage hands")]
     Hand {
         #[command(subcommand)]
@@ -28,10 +72,24 @@ pub enum Commands {
         subcommand: Option<ConfigCommands>,
     },
 
-    #[command(about = "Read a secret value from URI")]
+    #[command(
+        about = "Read a secret value from URI",
+        after_help = "Exit codes:\n  0  success\n  1  other error\n  2  invalid arguments\n  3  hand not found\n  4  card not found\n  5  deck locked or authentication failed\n  6  malformed URI"
+    )]
     Read {
         #[arg(help = "Secret URI (hc://[deck/]hand/card or op://[deck/]hand/card)")]
         uri: String,
+
+        #[arg(
+            short,
+            long,
+            value_name = "PATH",
+            help = "Write the value to a file (mode 600) instead of stdout"
+        )]
+        out: Option<String>,
+
+        #[arg(short = 'n', long, help = "Don't end the output with a newline")]
+        no_newline: bool,
     },
 
     #[command(about = "Inject secrets from template with URI references")]
@@ -44,6 +102,24 @@ pub enum Commands {
 
         #[arg(short = 'o', long, help = "Output file (default: stdout)")]
         output: Option<String>,
+
+        #[arg(
+            long,
+            requires = "input",
+            conflicts_with = "output",
+            help = "Write the rendered result back to the --input file"
+        )]
+        in_place: bool,
+
+        #[arg(
+            long,
+            requires = "in_place",
+            help = "Keep a .bak copy of the original file (with --in-place)"
+        )]
+        backup: bool,
+
+        #[arg(short = 'n', long, help = "Don't end stdout output with a newline")]
+        no_newline: bool,
     },
 
     #[command(about = "Run command with environment variables from URIs")]
@@ -51,6 +127,19 @@ pub enum Commands {
         #[arg(long, value_parser = parse_env_var, help = "Environment variable (KEY=hc://... or KEY=op://...)")]
         env: Vec<(String, String)>,
 
+        #[arg(
+            long,
+            value_name = "FILE",
+            help = "Load KEY=value lines (URIs resolved); --env takes precedence"
+        )]
+        env_file: Option<String>,
+
+        #[arg(
+            long,
+            help = "Prefix each variable with its hand's name (PASSWORD=hc://prod/myapp/password sets MYAPP_PASSWORD)"
+        )]
+        namespace: bool,
+
         #[arg(last = true, help = "Command and arguments to execute")]
         command: Vec<String>,
     },
@@ -72,29 +161,102 @@ pub enum Commands {
         #[arg(long, value_parser = parse_env_var, help = "Additional environment variable (KEY=value)")]
         env: Vec<(String, String)>,
 
+        #[arg(
+            long,
+            value_name = "FILE",
+            help = "Load KEY=value lines (URIs resolved); overrides cards, --env overrides it"
+        )]
+        env_file: Option<String>,
+
         #[arg(last = true, help = "Command and arguments to execute")]
         command: Vec<String>,
     },
 
     #[command(about = "Lock the deck (clear session)")]
-    Lock,
+    Lock {
+        #[arg(
+            long,
+            help = "Keep running and lock decks once their session has been idle past the timeout"
+        )]
+        idle: bool,
+
+        #[arg(
+            long,
+            default_value = "30",
+            value_parser = clap::value_parser!(u64).range(1..),
+            requires = "idle",
+            help = "Seconds between idle checks"
+        )]
+        interval: u64,
+    },
 
     #[command(about = "Show session status")]
     Status,
 
+    #[command(about = "Check the environment for common setup problems")]
+    Doctor,
+
+    #[command(about = "Register a legacy single-deck vault.enc as a deck and verify it")]
+    Migrate,
+
     #[command(about = "Export deck to JSON file")]
     Export {
-        #[arg(help = "Output file path")]
-        file: String,
+        #[arg(
+            required_unless_present = "stdout",
+            help = "Output file path ('-' for stdout)"
+        )]
+        file: Option<String>,
+
+        #[arg(
+            long,
+            conflicts_with = "file",
+            help = "Write the encrypted export to stdout as base64"
+        )]
+        stdout: bool,
+
+        #[arg(long, help = "Leave out the 'totp' hand holding your 2FA secrets")]
+        exclude_totp: bool,
+
+        #[arg(
+            long,
+            value_name = "NAME",
+            help = "Leave out a hand by name (repeatable)"
+        )]
+        exclude: Vec<String>,
+
+        #[arg(
+            long,
+            default_value = "encrypted",
+            help = "Output format: encrypted or env (plaintext export KEY='value' lines)"
+        )]
+        format: ExportFormat,
+
+        #[arg(long, help = "Allow plaintext output (required for --format env)")]
+        insecure: bool,
+
+        #[arg(
+            long,
+            value_name = "NAME",
+            help = "With --format env, export only this hand's cards without a name prefix"
+        )]
+        hand: Option<String>,
     },
 
     #[command(about = "Import hands from JSON file")]
     Import {
-        #[arg(help = "Input file path")]
+        #[arg(help = "Input file path ('-' for base64 from stdin)")]
         file: String,
 
         #[arg(long, help = "Overwrite existing hands")]
         overwrite: bool,
+
+        #[arg(
+            long,
+            value_name = "STRATEGY",
+            conflicts_with = "overwrite",
+            help = "How to handle existing hands: skip, overwrite, newest-wins, merge-cards"
+        )]
+        strategy: Option<MergeStrategy>,
     },
 
     #[command(about = "Manage TOTP (Time-based One-Time Password)")]
@@ -103,7 +265,7 @@ pub enum Commands {
         subcommand: TotpCommands,
     },
 
-    #[command(about = "Manage secret providers (GitHub, Cloudflare, etc.)")]
+    #[command(about = "Manage secret providers (GitHub, Cloudflare, Vercel)")]
     Provider {
         #[command(subcommand)]
         subcommand: ProviderCommands,
@@ -132,8 +294,30 @@ pub enum Commands {
         #[arg(long, help = "Exclude symbols")]
         no_symbols: bool,
 
-        #[arg(short, long, help = "Copy to clipboard")]
+        #[arg(
+            short,
+            long,
+            help = "Copy to clipboard (only the first when --count > 1)"
+        )]
         clip: bool,
+
+        #[arg(
+            long,
+            requires = "clip",
+            help = "Keep the copied password on the clipboard instead of clearing it"
+        )]
+        no_clear: bool,
+
+        #[arg(
+            long,
+            default_value_t = 1,
+            value_parser = clap::value_parser!(u32).range(1..=100),
+            help = "Number of passwords to generate, one per line (1-100)"
+        )]
+        count: u32,
+
+        #[arg(long, help = "Show a zxcvbn strength score and crack-time estimate")]
+        check: bool,
     },
 
     #[command(about = "Manage decks")]
@@ -150,7 +334,7 @@ pub enum Commands {
 
     #[command(about = "Generate shell completion script")]
     Completion {
-        #[arg(help = "Shell type (bash, zsh, fish)")]
+        #[arg(help = "Shell type (bash, zsh, fish, powershell, elvish)")]
         shell: String,
     },
 
@@ -159,6 +343,34 @@ pub enum Commands {
         #[arg(long)]
         deck: Option<String>,
     },
+
+    #[command(name = "__clear-clipboard", hide = true)]
+    __ClearClipboard {
+        #[arg(long)]
+        after: u64,
+    },
+}
+
+impl Commands {
+    /// Whether `--dry-run` covers this command: it writes only through the
+    /// deck's save or `skip_write_for_dry_run`, both of which dry-run skips,
+    /// or writes nothing.
+    pub fn supports_dry_run(&self) -> bool {
+        matches!(
+            self,
+            Commands::Hand { .. }
+                | Commands::Totp { .. }
+                | Commands::Import { .. }
+                | Commands::Current
+                | Commands::Read { .. }
+                | Commands::Inject { .. }
+                | Commands::Run { .. }
+                | Commands::Status
+                | Commands::Export { .. }
+                | Commands::Generate { .. }
+                | Commands::Completion { .. }
+        )
+    }
 }
 
 #[derive(Subcommand)]
@@ -174,6 +386,27 @@ pub enum HandCommands {
         #[arg(long, value_parser = parse_file_field, help = "Add card from file (key=path)")]
         file: Vec<(String, String)>,
 
+        #[arg(
+            long,
+            value_name = "KEY",
+            help = "Read card value for KEY from stdin (keeps secrets out of shell history)"
+        )]
+        field_stdin: Option<String>,
+
+        #[arg(
+            long,
+            value_name = "KEY",
+            help = "Read card value for KEY from the clipboard"
+        )]
+        from_clipboard: Option<String>,
+
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Add cards from a JSON object of string values ({\"key\": \"value\"}); -f/--file override it"
+        )]
+        json: Option<String>,
+
         #[arg(short, long, help = "Generate random password for 'password' card")]
         generate: bool,
 
@@ -204,8 +437,8 @@ pub enum HandCommands {
 
     #[command(about = "Get a hand")]
     Get {
-        #[arg(help = "Hand name")]
-        name: String,
+        #[arg(help = "Hand name (omit to pick from a list)")]
+        name: Option<String>,
 
         #[arg(
             short,
@@ -215,20 +448,99 @@ pub enum HandCommands {
         )]
         clip: Option<Option<String>>,
 
-        #[arg(long, help = "Show TOTP code")]
+        #[arg(
+            long,
+            conflicts_with = "clip",
+            help = "Copy every card as KEY=value lines to the clipboard (the whole secret set)"
+        )]
+        clip_all: bool,
+
+        #[arg(
+            long,
+            help = "Show a TOTP code from the hand's 'totp' or 'otp_secret' card (--clip copies the code)"
+        )]
         totp: bool,
 
         #[arg(long, help = "Show card values (requires password re-entry)")]
         show: bool,
+
+        #[arg(
+            long,
+            value_name = "CARD",
+            requires = "show",
+            conflicts_with_all = ["clip", "clip_all", "qr", "out", "totp"],
+            help = "With --show, print only this card's raw value (for scripts)"
+        )]
+        field: Option<String>,
+
+        #[arg(
+            long,
+            value_name = "CARD",
+            conflicts_with_all = ["show", "clip", "clip_all", "qr", "out"],
+            help = "Show prior values of a card (needs 'hand edit --track-values')"
+        )]
+        history: Option<String>,
+
+        #[arg(
+            long,
+            conflicts_with_all = ["show", "clip", "clip_all", "qr", "out", "history"],
+            help = "Print only the hand's notes"
+        )]
+        notes: bool,
+
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = 0,
+            conflicts_with = "show",
+            help = "Show the first N characters of masked cards (at most half of each value)"
+        )]
+        reveal_prefix: usize,
+
+        #[arg(
+            long,
+            value_name = "CARD",
+            help = "Display a card value as a QR code in the terminal"
+        )]
+        qr: Option<String>,
+
+        #[arg(
+            long,
+            value_name = "CARD=PATH",
+            value_parser = parse_out_target,
+            help = "Write a card's raw value to a file (mode 600), e.g. --out key=~/.ssh/id_ed25519"
+        )]
+        out: Vec<(String, String)>,
+
+        #[arg(
+            short = 'n',
+            long,
+            help = "Don't end --field or --notes output with a newline"
+        )]
+        no_newline: bool,
     },
 
     #[command(about = "List all hands")]
-    List,
+    List {
+        #[arg(long, help = "Also print every card value (asks for confirmation)")]
+        show: bool,
+
+        #[arg(
+            long,
+            value_name = "ORDER",
+            default_value = "name",
+            help = "Sort by name, created or updated"
+        )]
+        sort: HandSort,
+
+        #[arg(long, help = "Reverse the sort order")]
+        reverse: bool,
+    },
 
     #[command(about = "Edit a hand")]
     Edit {
-        #[arg(help = "Hand name")]
-        name: String,
+        #[arg(help = "Hand name (omit to pick from a list)")]
+        name: Option<String>,
 
         #[arg(short, long, help = "Interactive mode")]
         interactive: bool,
@@ -239,16 +551,137 @@ pub enum HandCommands {
         #[arg(long, value_parser = parse_file_field, help = "Add or update card from file (key=path)")]
         file: Vec<(String, String)>,
 
+        #[arg(
+            long,
+            value_name = "KEY",
+            conflicts_with = "interactive",
+            help = "Add or update card KEY from the clipboard"
+        )]
+        from_clipboard: Option<String>,
+
         #[arg(short = 'd', long = "rm-card", help = "Remove card by key")]
         rm_card: Vec<String>,
+
+        #[arg(short, long, help = "Regenerate the 'password' card")]
+        generate: bool,
+
+        #[arg(long, help = "Generated password length (default: 20)")]
+        gen_length: Option<usize>,
+
+        #[arg(short = 'm', long, help = "Generate memorable passphrase")]
+        gen_memorable: bool,
+
+        #[arg(short = 'w', long, help = "Number of words in passphrase (default: 4)")]
+        gen_words: Option<usize>,
+
+        #[arg(long, help = "Exclude uppercase from generated password")]
+        gen_no_uppercase: bool,
+
+        #[arg(long, help = "Exclude lowercase from generated password")]
+        gen_no_lowercase: bool,
+
+        #[arg(long, help = "Exclude digits from generated password")]
+        gen_no_digits: bool,
+
+        #[arg(long, help = "Exclude symbols from generated password")]
+        gen_no_symbols: bool,
+
+        #[arg(
+            short,
+            long,
+            requires = "generate",
+            help = "Copy the generated password to clipboard"
+        )]
+        clip: bool,
+
+        #[arg(
+            long,
+            help = "Keep replaced card values so 'hand get --history' can show them"
+        )]
+        track_values: bool,
+
+        #[arg(
+            long,
+            conflicts_with = "track_values",
+            help = "Stop keeping replaced card values and delete the ones already stored"
+        )]
+        no_track_values: bool,
     },
 
     #[command(about = "Remove a hand")]
     Remove {
+        #[arg(help = "Hand name (omit, without --match, to pick from a list)")]
+        name: Option<String>,
+
+        #[arg(
+            long = "match",
+            value_name = "GLOB",
+            conflicts_with = "name",
+            help = "Remove all hands whose name matches a glob (e.g., 'old-*')"
+        )]
+        pattern: Option<String>,
+    },
+
+    #[command(about = "Copy a card to the clipboard without printing the hand")]
+    Clip {
+        #[arg(help = "Hand name")]
+        name: String,
+
+        #[arg(
+            help = "Card key (default: asks if several look secret, else password or the first card)"
+        )]
+        card: Option<String>,
+    },
+
+    #[command(about = "Open a hand's url/uri card in the default browser")]
+    Open {
+        #[arg(help = "Hand name")]
+        name: String,
+
+        #[arg(
+            short,
+            long,
+            help = "Copy the password card to clipboard before opening"
+        )]
+        clip: bool,
+    },
+
+    #[command(about = "Copy the password, then open the hand's url in the browser")]
+    Login {
         #[arg(help = "Hand name")]
         name: String,
     },
 
+    #[command(about = "Show the next unused recovery code and mark it consumed")]
+    Recovery {
+        #[arg(help = "Hand name")]
+        name: String,
+    },
+
+    #[command(about = "Find hands whose card matches a value (prints names only)")]
+    Find {
+        #[arg(short, long, value_parser = parse_field, help = "Card to match (key=value)")]
+        field: (String, String),
+
+        #[arg(long, help = "Match values containing the text instead of equal to it")]
+        contains: bool,
+    },
+
+    #[command(about = "Show the change history of a hand (card keys only)")]
+    History {
+        #[arg(help = "Hand name")]
+        name: String,
+    },
+
+    #[command(about = "Duplicate a hand under a new name")]
+    Duplicate {
+        #[arg(help = "Hand name to duplicate")]
+        name: String,
+
+        #[arg(help = "New hand name (prompted if omitted)")]
+        new_name: Option<String>,
+    },
+
     #[command(about = "Manage cards within a hand")]
     Card {
         #[command(subcommand)]
@@ -266,6 +699,45 @@ pub enum CardCommands {
         #[arg(help = "Card key to remove")]
         card: String,
     },
+
+    #[command(
+        alias = "move",
+        about = "Rename a card within a hand, keeping its value"
+    )]
+    Mv {
+        #[arg(help = "Hand name")]
+        hand: String,
+
+        #[arg(help = "Current card key")]
+        old_key: String,
+
+        #[arg(help = "New card key")]
+        new_key: String,
+    },
+
+    #[command(about = "Rename a card in every hand that has it, or in one hand")]
+    RenameField {
+        #[arg(help = "Current card key")]
+        old_key: String,
+
+        #[arg(help = "New card key")]
+        new_key: String,
+
+        #[arg(
+            long,
+            required_unless_present = "hand",
+            help = "Rename in every hand (hands that already have the new key are skipped)"
+        )]
+        all: bool,
+
+        #[arg(
+            long,
+            value_name = "NAME",
+            conflicts_with = "all",
+            help = "Rename in this hand only"
+        )]
+        hand: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -281,6 +753,56 @@ pub enum ConfigCommands {
         #[arg(help = "Timeout in minutes")]
         minutes: u64,
     },
+
+    #[command(about = "Enable or disable biometric authentication (macOS)")]
+    Biometric {
+        #[arg(value_parser = ["on", "off"], help = "on or off")]
+        state: String,
+    },
+
+    #[command(about = "Show the current configuration")]
+    Show {
+        #[arg(long, help = "Output as JSON")]
+        json: bool,
+    },
+
+    #[command(about = "Print a single configuration value")]
+    Get {
+        #[arg(help = "Config key (deck_path, session_timeout_minutes, enable_biometric)")]
+        key: String,
+    },
+
+    #[command(about = "Set a configuration value")]
+    Set {
+        #[arg(help = "Config key (deck_path, session_timeout_minutes, enable_biometric)")]
+        key: String,
+
+        #[arg(help = "New value")]
+        value: String,
+    },
+
+    #[command(about = "Show the key-derivation (Argon2id) cost")]
+    Kdf {
+        #[arg(long, help = "Time several cost settings on this machine")]
+        benchmark: bool,
+
+        #[arg(
+            long,
+            value_name = "MS",
+            default_value_t = 500,
+            requires = "benchmark",
+            help = "Slowest acceptable unlock time for the recommendation"
+        )]
+        target_ms: u64,
+    },
+
+    #[command(
+        about = "Restore the default configuration (keeps deck_path and vaults_dir unless --all)"
+    )]
+    Reset {
+        #[arg(long, help = "Also reset deck_path and vaults_dir")]
+        all: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -290,6 +812,12 @@ pub enum ProviderCommands {
 
     #[command(about = "Add a new provider configuration")]
     Add {
+        #[arg(
+            long,
+            help = "Check the credentials against the provider before saving"
+        )]
+        verify: bool,
+
         #[command(subcommand)]
         provider: ProviderAddCommands,
     },
@@ -302,6 +830,12 @@ pub enum ProviderCommands {
         #[arg(help = "Provider ID")]
         provider_id: String,
 
+        #[arg(
+            long,
+            help = "Check the credentials against the provider before saving"
+        )]
+        verify: bool,
+
         #[command(subcommand)]
         provider: ProviderAddCommands,
     },
@@ -315,6 +849,15 @@ pub enum ProviderCommands {
         provider_id: String,
     },
 
+    #[command(about = "Check that provider credentials work (read-only)")]
+    Test {
+        #[arg(help = "Provider type")]
+        provider_type: String,
+
+        #[arg(help = "Provider ID")]
+        provider_id: String,
+    },
+
     #[command(about = "Manage secrets in provider")]
     Secrets {
         #[command(subcommand)]
@@ -331,6 +874,9 @@ pub enum ProviderSecretsCommands {
 
         #[arg(help = "Provider ID")]
         provider_id: String,
+
+        #[arg(long, help = "Output as JSON")]
+        json: bool,
     },
 
     #[command(about = "Add secret(s) to provider")]
@@ -362,6 +908,21 @@ pub enum ProviderSecretsCommands {
         #[arg(help = "Secret name to delete")]
         secret_name: String,
     },
+
+    #[command(about = "Make a provider's secrets match a hand's cards")]
+    Sync {
+        #[arg(help = "Provider type")]
+        provider_type: String,
+
+        #[arg(help = "Provider ID")]
+        provider_id: String,
+
+        #[arg(help = "Hand name")]
+        hand: String,
+
+        #[arg(long, help = "Delete provider secrets that have no matching card")]
+        prune: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -374,7 +935,10 @@ pub enum ProviderAddCommands {
         #[arg(long, help = "GitHub repository (owner/repo)")]
         repo: String,
 
-        #[arg(long, help = "GitHub Personal Access Token")]
+        #[arg(
+            long,
+            help = "GitHub Personal Access Token (or env:VAR to read at use time)"
+        )]
         token: String,
     },
 
@@ -389,8 +953,28 @@ pub enum ProviderAddCommands {
         #[arg(long, help = "Worker name")]
         worker_name: String,
 
-        #[arg(long, help = "Cloudflare API Token")]
+        #[arg(long, help = "Cloudflare API Token (or env:VAR to read at use time)")]
+        token: String,
+    },
+
+    #[command(about = "Add Vercel project environment variables provider")]
+    Vercel {
+        #[arg(help = "Provider ID (e.g., my-site)")]
+        provider_id: String,
+
+        #[arg(long, help = "Vercel project ID or name")]
+        project_id: String,
+
+        #[arg(long, help = "Vercel access token (or env:VAR to read at use time)")]
         token: String,
+
+        #[arg(
+            long,
+            default_value = "production",
+            value_parser = clap::builder::PossibleValuesParser::new(VERCEL_TARGETS),
+            help = "Environment the variables are created in"
+        )]
+        target: String,
     },
 }
 
@@ -401,14 +985,68 @@ pub enum TotpCommands {
         #[arg(help = "Hand name")]
         card: String,
 
-        #[arg(help = "TOTP secret (base32 encoded)")]
-        secret: String,
+        #[arg(
+            required_unless_present = "qr_image",
+            help = "TOTP secret (base32 encoded or otpauth:// URI)"
+        )]
+        secret: Option<String>,
+
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with = "secret",
+            help = "Read the otpauth:// URI from a QR code image (PNG or JPEG)"
+        )]
+        qr_image: Option<String>,
+
+        #[arg(
+            long,
+            value_name = "ALGORITHM",
+            help = "Hash algorithm: sha1, sha256 or sha512 (default: sha1)"
+        )]
+        algorithm: Option<TotpAlgorithm>,
+
+        #[arg(
+            long,
+            value_parser = clap::value_parser!(u32).range(6..=8),
+            help = "Number of digits in the code (default: 6)"
+        )]
+        digits: Option<u32>,
+
+        #[arg(
+            long,
+            value_parser = clap::value_parser!(u64).range(1..=300),
+            help = "Time step in seconds (default: 30)"
+        )]
+        period: Option<u64>,
     },
 
     #[command(about = "Get TOTP code (displays and copies to clipboard)")]
     Get {
         #[arg(help = "Hand name")]
         card: String,
+
+        #[arg(
+            short,
+            long,
+            help = "Keep the code and a countdown on screen, refreshing until Ctrl-C"
+        )]
+        watch: bool,
+
+        #[arg(
+            long,
+            value_name = "SECONDS",
+            conflicts_with = "watch",
+            value_parser = clap::value_parser!(u64).range(1..=600),
+            help = "Also show codes for adjacent windows within this clock skew (1-600)"
+        )]
+        skew: Option<u64>,
+    },
+
+    #[command(about = "Check a TOTP secret and show its current code without storing it")]
+    Validate {
+        #[arg(help = "TOTP secret (base32 encoded or otpauth:// URI)")]
+        secret: String,
     },
 
     #[command(about = "Remove TOTP secret from a hand")]
@@ -423,12 +1061,27 @@ pub enum DeckCommands {
     #[command(about = "List all decks")]
     List,
 
+    #[command(about = "Show a deck's path, size, timestamps and session status")]
+    Info {
+        #[arg(help = "Deck name")]
+        name: String,
+    },
+
     #[command(about = "Create a new deck")]
     Create {
         #[arg(help = "Deck name")]
         name: String,
     },
 
+    #[command(about = "Rename a deck")]
+    Rename {
+        #[arg(help = "Current deck name")]
+        old_name: String,
+
+        #[arg(help = "New deck name")]
+        new_name: String,
+    },
+
     #[command(about = "Delete a deck")]
     Delete {
         #[arg(help = "Deck name")]
@@ -442,6 +1095,12 @@ pub enum DeckCommands {
     Use {
         #[arg(help = "Deck name")]
         name: String,
+
+        #[arg(
+            long,
+            help = "Select the deck for this directory only (writes .holecard-deck)"
+        )]
+        local: bool,
     },
 
     #[command(about = "Move hand to another deck")]
@@ -462,8 +1121,69 @@ pub enum DeckCommands {
         to_hand: String,
     },
 
+    #[command(about = "Merge every hand of one deck into another")]
+    Merge {
+        #[arg(help = "Source deck name")]
+        source: String,
+
+        #[arg(help = "Target deck name")]
+        target: String,
+
+        #[arg(
+            long,
+            value_name = "STRATEGY",
+            default_value = "skip",
+            help = "How to handle existing hands: skip, overwrite, newest-wins, merge-cards"
+        )]
+        strategy: MergeStrategy,
+
+        #[arg(long, help = "Delete the source deck after a complete merge")]
+        delete_source: bool,
+    },
+
     #[command(about = "Change master password")]
-    Passwd,
+    Passwd {
+        #[arg(
+            long,
+            help = "Also replace the secret key (re-encrypts every deck; asks for each deck's password)"
+        )]
+        rotate_secret_key: bool,
+    },
+
+    #[command(about = "Show deck statistics (no secret values)")]
+    Stats,
+
+    #[command(about = "Rewrite the deck file from scratch with a fresh nonce")]
+    Compact {
+        #[arg(
+            long,
+            help = "Also rotate the key-derivation salt (asks for the master password)"
+        )]
+        new_salt: bool,
+    },
+
+    #[command(about = "Export every deck into a single encrypted archive")]
+    ExportAll {
+        #[arg(help = "Output file path")]
+        file: String,
+    },
+
+    #[command(about = "Restore decks from an archive created by export-all")]
+    ImportAll {
+        #[arg(help = "Input file path")]
+        file: String,
+
+        #[arg(long, help = "Overwrite existing hands")]
+        overwrite: bool,
+
+        #[arg(
+            long,
+            value_name = "STRATEGY",
+            conflicts_with = "overwrite",
+            help = "How to handle existing hands: skip, overwrite, newest-wins, merge-cards"
+        )]
+        strategy: Option<MergeStrategy>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -499,6 +1219,24 @@ pub enum SshCommands {
 
         #[arg(long, help = "Passphrase for private key (optional)")]
         passphrase: Option<String>,
+
+        #[arg(
+            long,
+            help = "Jump host(s) for ProxyJump (user@bastion or a hand name, comma-separated)"
+        )]
+        proxy_jump: Option<String>,
+
+        #[arg(
+            long,
+            help = "Pin the host key (known_hosts line or 'keytype base64key')"
+        )]
+        host_key: Option<String>,
+
+        #[arg(
+            long,
+            help = "Fail if the hand already exists instead of offering to update it"
+        )]
+        no_update: bool,
     },
 
     #[command(about = "Load SSH key into ssh-agent")]
@@ -512,8 +1250,18 @@ pub enum SshCommands {
 
     #[command(about = "Remove SSH key from ssh-agent")]
     Unload {
-        #[arg(help = "Hand name or public key fingerprint")]
-        name: String,
+        #[arg(
+            required_unless_present = "all",
+            help = "Hand name or public key fingerprint"
+        )]
+        name: Option<String>,
+
+        #[arg(
+            long,
+            conflicts_with = "name",
+            help = "Remove all identities from ssh-agent"
+        )]
+        all: bool,
     },
 
     #[command(about = "List loaded SSH keys in ssh-agent")]
@@ -524,9 +1272,57 @@ pub enum SshCommands {
         #[arg(help = "Hand name or alias (e.g., git@github.com)")]
         target: String,
 
+        #[arg(
+            long,
+            help = "Show the target resolved from ~/.ssh/config and confirm before connecting"
+        )]
+        confirm: bool,
+
         #[arg(last = true, help = "Additional SSH arguments")]
         ssh_args: Vec<String>,
     },
+
+    #[command(about = "Copy files with scp using SSH hand credentials")]
+    Scp {
+        #[arg(help = "Hand name or alias (e.g., git@github.com)")]
+        target: String,
+
+        #[arg(help = "Local path")]
+        local: String,
+
+        #[arg(help = "Remote path on the host")]
+        remote: String,
+
+        #[arg(long, help = "Copy from remote to local (default: local to remote)")]
+        download: bool,
+
+        #[arg(short, long, help = "Copy directories recursively")]
+        recursive: bool,
+
+        #[arg(last = true, help = "Additional scp arguments")]
+        scp_args: Vec<String>,
+    },
+
+    #[command(about = "Sync files with rsync using SSH hand credentials")]
+    Rsync {
+        #[arg(help = "Hand name or alias (e.g., git@github.com)")]
+        target: String,
+
+        #[arg(help = "Local path")]
+        local: String,
+
+        #[arg(help = "Remote path on the host")]
+        remote: String,
+
+        #[arg(long, help = "Sync from remote to local (default: local to remote)")]
+        download: bool,
+
+        #[arg(
+            last = true,
+            help = "Additional rsync arguments (e.g., -- -avz --delete)"
+        )]
+        rsync_args: Vec<String>,
+    },
 }
 
 fn parse_field(s: &str) -> Result<(String, String), String> {
@@ -534,9 +1330,19 @@ fn parse_field(s: &str) -> Result<(String, String), String> {
     if parts.len() != 2 {
         return Err(format!("Invalid field format: '{}'. Expected key=value", s));
     }
+    validate_name(parts[0]).map_err(|e| e.to_string())?;
     Ok((parts[0].to_string(), parts[1].to_string()))
 }
 
+fn parse_out_target(s: &str) -> Result<(String, String), String> {
+    let (key, path) = s
+        .split_once('=')
+        .filter(|(key, path)| !key.is_empty() && !path.is_empty())
+        .ok_or_else(|| format!("Invalid output format: '{}'. Expected card=path", s))?;
+
+    Ok((key.to_string(), path.to_string()))
+}
+
 fn parse_file_field(s: &str) -> Result<(String, String), String> {
     let parts: Vec<&str> = s.splitn(2, '=').collect();
     if parts.len() != 2 {
@@ -545,6 +1351,7 @@ fn parse_file_field(s: &str) -> Result<(String, String), String> {
 
     let key = parts[0].to_string();
     let path = parts[1];
+    validate_name(&key).map_err(|e| e.to_string())?;
 
     let expanded_path = if path.starts_with('~') {
         path.replacen(
@@ -556,9 +1363,21 @@ fn parse_file_field(s: &str) -> Result<(String, String), String> {
         path.to_string()
     };
 
-    let content = std::fs::read_to_string(&expanded_path)
+    let bytes = std::fs::read(&expanded_path)
         .map_err(|e| format!("Failed to read file '{}': {}", expanded_path, e))?;
 
+    // Cards hold text, so binary files (DER certificates, ...) need encoding first.
+    let content = String::from_utf8(bytes).map_err(|e| {
+        format!(
+            "File '{}' is not UTF-8 text (invalid byte at offset {}). \
+             Store binary files base64-encoded, e.g. -f {}=\"$(base64 < {})\"",
+            expanded_path,
+            e.utf8_error().valid_up_to(),
+            key,
+            path
+        )
+    })?;
+
     Ok((key, content))
 }
 
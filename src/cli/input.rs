@@ -1,34 +1,48 @@
 use anyhow::{Context, Result};
-use dialoguer::{theme::ColorfulTheme, Confirm, Editor, Input, Password, Select};
+use dialoguer::theme::{ColorfulTheme, SimpleTheme, Theme};
+use dialoguer::{Confirm, Editor, FuzzySelect, Input, Password, Select};
 use std::collections::HashMap;
 
-use crate::domain::Hand;
+use crate::cli::output;
+use crate::config::MasterPasswordPolicy;
+use crate::domain::{validate_name, Hand};
+
+/// Prompt theme shared by every prompt; plain when colors are disabled.
+fn theme() -> Box<dyn Theme> {
+    if output::colors_enabled() {
+        Box::new(ColorfulTheme::default())
+    } else {
+        Box::new(SimpleTheme)
+    }
+}
 
 pub fn prompt_master_password() -> Result<String> {
-    Password::with_theme(&ColorfulTheme::default())
+    Password::with_theme(theme().as_ref())
         .with_prompt("Master Password")
         .interact()
         .context("Failed to read master password")
 }
 
-pub fn prompt_master_password_confirm() -> Result<String> {
-    let password = Password::with_theme(&ColorfulTheme::default())
+pub fn prompt_master_password_confirm(policy: &MasterPasswordPolicy) -> Result<String> {
+    let password = Password::with_theme(theme().as_ref())
         .with_prompt("Master Password")
         .with_confirmation("Confirm Master Password", "Passwords do not match")
         .interact()
         .context("Failed to read master password")?;
 
-    if password.len() < 12 {
-        return Err(anyhow::anyhow!(
-            "Master password must be at least 12 characters"
-        ));
+    let unmet = policy.unmet_requirements(&password);
+    if !unmet.is_empty() {
+        anyhow::bail!(
+            "Master password does not meet the password policy. Missing:\n  • {}",
+            unmet.join("\n  • ")
+        );
     }
 
     Ok(password)
 }
 
 pub fn prompt_hand_name() -> Result<String> {
-    Input::with_theme(&ColorfulTheme::default())
+    Input::with_theme(theme().as_ref())
         .with_prompt("Hand name")
         .interact_text()
         .context("Failed to read hand name")
@@ -40,7 +54,7 @@ pub fn prompt_cards() -> Result<HashMap<String, String>> {
     println!("\nEnter cards (leave name empty to finish):");
 
     loop {
-        let key: String = Input::with_theme(&ColorfulTheme::default())
+        let key: String = Input::with_theme(theme().as_ref())
             .with_prompt("Card name")
             .allow_empty(true)
             .interact_text()
@@ -50,7 +64,7 @@ pub fn prompt_cards() -> Result<HashMap<String, String>> {
             break;
         }
 
-        let value: String = Password::with_theme(&ColorfulTheme::default())
+        let value: String = Password::with_theme(theme().as_ref())
             .with_prompt(format!("{} value", key))
             .allow_empty_password(true)
             .interact()
@@ -63,7 +77,7 @@ pub fn prompt_cards() -> Result<HashMap<String, String>> {
 }
 
 pub fn prompt_notes() -> Result<Option<String>> {
-    let notes: String = Input::with_theme(&ColorfulTheme::default())
+    let notes: String = Input::with_theme(theme().as_ref())
         .with_prompt("Notes (optional)")
         .allow_empty(true)
         .interact_text()
@@ -76,17 +90,36 @@ pub fn prompt_notes() -> Result<Option<String>> {
     }
 }
 
-#[allow(dead_code)]
-pub fn prompt_confirm_reinit() -> Result<bool> {
-    Confirm::with_theme(&ColorfulTheme::default())
-        .with_prompt("⚠ Deck already exists. Reinitialize? This will DELETE ALL existing data!")
+pub fn prompt_confirm(prompt: &str) -> Result<bool> {
+    Confirm::with_theme(theme().as_ref())
+        .with_prompt(prompt)
         .default(false)
         .interact()
         .context("Failed to read confirmation")
 }
 
+/// Fuzzy-search `names` and pick one; returns its index
+pub fn prompt_pick_hand(names: &[&str]) -> Result<usize> {
+    FuzzySelect::with_theme(theme().as_ref())
+        .with_prompt("Hand (type to filter)")
+        .items(names)
+        .default(0)
+        .interact()
+        .context("Failed to read hand selection")
+}
+
+/// Pick one of `keys`, starting on `password` if present; returns its index
+pub fn prompt_select_card(prompt: &str, keys: &[&str]) -> Result<usize> {
+    Select::with_theme(theme().as_ref())
+        .with_prompt(prompt)
+        .items(keys)
+        .default(keys.iter().position(|k| *k == "password").unwrap_or(0))
+        .interact()
+        .context("Failed to read card selection")
+}
+
 pub fn prompt_export_password() -> Result<String> {
-    let password = Password::with_theme(&ColorfulTheme::default())
+    let password = Password::with_theme(theme().as_ref())
         .with_prompt("Export Password")
         .with_confirmation("Confirm Export Password", "Passwords do not match")
         .interact()
@@ -100,7 +133,7 @@ pub fn prompt_export_password() -> Result<String> {
 }
 
 pub fn prompt_import_password() -> Result<String> {
-    Password::with_theme(&ColorfulTheme::default())
+    Password::with_theme(theme().as_ref())
         .with_prompt("Import Password")
         .interact()
         .context("Failed to read import password")
@@ -125,7 +158,7 @@ pub fn prompt_edit_menu(hand: &Hand) -> Result<EditAction> {
         options.insert(1, "Delete card");
     }
 
-    let selection = Select::with_theme(&ColorfulTheme::default())
+    let selection = Select::with_theme(theme().as_ref())
         .with_prompt("Select action")
         .items(&options)
         .default(0)
@@ -136,7 +169,7 @@ pub fn prompt_edit_menu(hand: &Hand) -> Result<EditAction> {
 
     match selection {
         0 if !card_keys.is_empty() => {
-            let card_selection = Select::with_theme(&ColorfulTheme::default())
+            let card_selection = Select::with_theme(theme().as_ref())
                 .with_prompt("Select card to edit")
                 .items(&card_keys)
                 .interact()
@@ -144,7 +177,7 @@ pub fn prompt_edit_menu(hand: &Hand) -> Result<EditAction> {
             Ok(EditAction::EditCard(card_keys[card_selection].clone()))
         }
         1 if !card_keys.is_empty() => {
-            let card_selection = Select::with_theme(&ColorfulTheme::default())
+            let card_selection = Select::with_theme(theme().as_ref())
                 .with_prompt("Select card to delete")
                 .items(&card_keys)
                 .interact()
@@ -172,7 +205,7 @@ pub fn prompt_card_value(key: &str) -> Result<String> {
 
         Ok(cleaned.trim().to_string())
     } else {
-        Password::with_theme(&ColorfulTheme::default())
+        Password::with_theme(theme().as_ref())
             .with_prompt(format!("New value for '{}'", key))
             .allow_empty_password(true)
             .interact()
@@ -181,12 +214,13 @@ pub fn prompt_card_value(key: &str) -> Result<String> {
 }
 
 pub fn prompt_new_card() -> Result<(String, String)> {
-    let key: String = Input::with_theme(&ColorfulTheme::default())
+    let key: String = Input::with_theme(theme().as_ref())
         .with_prompt("Card name")
+        .validate_with(|key: &String| validate_name(key).map_err(|e| e.to_string()))
         .interact_text()
         .context("Failed to read card name")?;
 
-    let value: String = Password::with_theme(&ColorfulTheme::default())
+    let value: String = Password::with_theme(theme().as_ref())
         .with_prompt(format!("{} value", key))
         .allow_empty_password(true)
         .interact()
@@ -1,4 +1,6 @@
 pub mod commands;
 pub mod input;
+pub mod output;
+pub mod qr;
 
 pub use commands::ConfigCommands;
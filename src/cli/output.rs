@@ -0,0 +1,120 @@
+//! Status output that honours the global `--quiet`, `--no-color` and
+//! `--verbose` flags.
+//!
+//! Decorative confirmations ("✓ ...") go through `status!` to stderr. Data
+//! output (secret values, rendered templates, listings) goes to stdout;
+//! single payloads meant for `$(...)` use `print_payload`.
+
+use anyhow::{Context, Result};
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+static COLOR: AtomicBool = AtomicBool::new(true);
+
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Disable colored output when `--no-color` is passed or `NO_COLOR` is set
+/// to a non-empty value (https://no-color.org).
+pub fn init_color(no_color_flag: bool) {
+    let no_color_env = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    let enabled = !(no_color_flag || no_color_env);
+
+    COLOR.store(enabled, Ordering::Relaxed);
+    if !enabled {
+        console::set_colors_enabled(false);
+        console::set_colors_enabled_stderr(false);
+    }
+}
+
+/// Send this crate's `log::debug!` output to stderr under `--verbose`.
+/// Dependencies stay silent so request headers (tokens) are never logged.
+pub fn init_logging(verbose: bool) {
+    let level = if verbose {
+        log::LevelFilter::Debug
+    } else {
+        log::LevelFilter::Off
+    };
+    env_logger::Builder::new()
+        .filter_module(env!("CARGO_CRATE_NAME"), level)
+        .format_timestamp(None)
+        .format_target(false)
+        .init();
+}
+
+pub fn colors_enabled() -> bool {
+    COLOR.load(Ordering::Relaxed)
+}
+
+/// Print a non-essential status line to stderr unless `--quiet` is set, so
+/// it never mixes with data on stdout.
+macro_rules! status {
+    ($($arg:tt)*) => {
+        if !$crate::cli::output::is_quiet() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+pub(crate) use status;
+
+/// Write a payload to stdout ending in exactly one newline, or none with
+/// `newline == false`. A value's own trailing newline is not doubled.
+pub fn print_payload(value: &str, newline: bool) -> Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    stdout
+        .write_all(payload_text(value, newline).as_bytes())
+        .and_then(|_| stdout.flush())
+        .context("Failed to write to stdout")
+}
+
+fn payload_text(value: &str, newline: bool) -> String {
+    let body = value.strip_suffix('\n').unwrap_or(value);
+    if newline {
+        format!("{}\n", body)
+    } else {
+        body.to_string()
+    }
+}
+
+/// Mask a secret for display. Shows at most `reveal` leading characters (never
+/// more than half the value) followed by one `*` per hidden character; with
+/// `reveal == 0` a fixed-width mask is used so the length is not disclosed.
+pub fn mask_value(value: &str, reveal: usize) -> String {
+    if reveal == 0 {
+        return "******".to_string();
+    }
+
+    let len = value.chars().count();
+    let shown = reveal.min(len / 2);
+    let prefix: String = value.chars().take(shown).collect();
+    format!("{}{}", prefix, "*".repeat(len - shown))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payload_text() {
+        assert_eq!(payload_text("s3cret", true), "s3cret\n");
+        assert_eq!(payload_text("-----KEY-----\n", true), "-----KEY-----\n");
+        assert_eq!(payload_text("s3cret", false), "s3cret");
+        assert_eq!(payload_text("-----KEY-----\n", false), "-----KEY-----");
+        assert_eq!(payload_text("a\n\n", true), "a\n\n");
+    }
+
+    #[test]
+    fn test_mask_value() {
+        assert_eq!(mask_value("hunter2secret", 0), "******");
+        assert_eq!(mask_value("hunter2secret", 3), "hun**********");
+        assert_eq!(mask_value("abcd", 4), "ab**");
+        assert_eq!(mask_value("pässwörd", 2), "pä******");
+    }
+}
@@ -0,0 +1,15 @@
+use anyhow::{Context, Result};
+use qrcode::render::unicode::Dense1x2;
+use qrcode::QrCode;
+
+/// Render a value as a QR code made of Unicode half blocks for the terminal.
+pub fn render_terminal_qr(value: &str) -> Result<String> {
+    let code = QrCode::new(value.as_bytes()).context("Value is too long to encode as a QR code")?;
+
+    Ok(code
+        .render::<Dense1x2>()
+        .dark_color(Dense1x2::Light)
+        .light_color(Dense1x2::Dark)
+        .quiet_zone(true)
+        .build())
+}
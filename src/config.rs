@@ -3,19 +3,124 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::infrastructure::{DeckConfigOverrides, DeckRegistry};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     #[serde(alias = "vault_path")]
     pub deck_path: PathBuf,
+    /// Where new deck files are created (defaults to the config directory)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vaults_dir: Option<PathBuf>,
     pub session_timeout_minutes: u64,
     #[serde(default = "default_enable_biometric")]
     pub enable_biometric: bool,
+    /// Card keys shown in plain text by `hc hand get` without `--show`
+    #[serde(default = "default_non_secret_cards")]
+    pub non_secret_cards: Vec<String>,
+    /// Lifetime of keys auto-loaded by `hc ssh connect` (0 = no limit)
+    #[serde(default = "default_ssh_key_lifetime_seconds")]
+    pub ssh_key_lifetime_seconds: u32,
+    /// Remove the auto-loaded key from ssh-agent once `hc ssh connect` exits
+    #[serde(default)]
+    pub ssh_unload_on_exit: bool,
+    /// Re-derive the deck key with a fresh salt on saves made after typing the
+    /// master password (cached-session saves only have the derived key)
+    #[serde(default)]
+    pub rotate_salt_on_save: bool,
+    #[serde(default)]
+    pub master_password_policy: MasterPasswordPolicy,
+}
+
+/// Requirements enforced when a master password is set or changed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MasterPasswordPolicy {
+    #[serde(default = "default_min_length")]
+    pub min_length: usize,
+    #[serde(default)]
+    pub require_uppercase: bool,
+    #[serde(default)]
+    pub require_lowercase: bool,
+    #[serde(default)]
+    pub require_digit: bool,
+    #[serde(default)]
+    pub require_symbol: bool,
+}
+
+fn default_min_length() -> usize {
+    12
+}
+
+impl Default for MasterPasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: default_min_length(),
+            require_uppercase: false,
+            require_lowercase: false,
+            require_digit: false,
+            require_symbol: false,
+        }
+    }
+}
+
+impl MasterPasswordPolicy {
+    /// Human-readable list of every requirement in the policy
+    pub fn requirements(&self) -> Vec<String> {
+        let mut requirements = vec![format!("At least {} characters", self.min_length)];
+        let classes = [
+            (self.require_uppercase, "An uppercase letter"),
+            (self.require_lowercase, "A lowercase letter"),
+            (self.require_digit, "A digit"),
+            (self.require_symbol, "A symbol"),
+        ];
+        requirements.extend(
+            classes
+                .iter()
+                .filter(|(required, _)| *required)
+                .map(|(_, label)| label.to_string()),
+        );
+        requirements
+    }
+
+    /// Requirements the password does not meet (empty if it passes)
+    pub fn unmet_requirements(&self, password: &str) -> Vec<String> {
+        let has = |pred: fn(&char) -> bool| password.chars().any(|c| pred(&c));
+        let mut unmet = Vec::new();
+
+        if password.chars().count() < self.min_length {
+            unmet.push(format!("At least {} characters", self.min_length));
+        }
+        if self.require_uppercase && !has(char::is_ascii_uppercase) {
+            unmet.push("An uppercase letter".to_string());
+        }
+        if self.require_lowercase && !has(char::is_ascii_lowercase) {
+            unmet.push("A lowercase letter".to_string());
+        }
+        if self.require_digit && !has(char::is_ascii_digit) {
+            unmet.push("A digit".to_string());
+        }
+        if self.require_symbol && !has(|c| !c.is_alphanumeric() && !c.is_whitespace()) {
+            unmet.push("A symbol".to_string());
+        }
+        unmet
+    }
 }
 
 fn default_enable_biometric() -> bool {
     cfg!(target_os = "macos")
 }
 
+fn default_ssh_key_lifetime_seconds() -> u32 {
+    3600
+}
+
+fn default_non_secret_cards() -> Vec<String> {
+    ["username", "email", "url", "host", "port"]
+        .iter()
+        .map(|k| k.to_string())
+        .collect()
+}
+
 impl Config {
     pub fn load(config_dir: &Path) -> Result<Self> {
         let config_path = config_dir.join("config.toml");
@@ -31,6 +136,40 @@ impl Config {
         toml::from_str(&content).context("Failed to parse config file")
     }
 
+    /// Directory holding deck files: `vaults_dir` if set, else the config directory
+    pub fn decks_dir(&self, config_dir: &Path) -> PathBuf {
+        self.vaults_dir
+            .clone()
+            .unwrap_or_else(|| config_dir.to_path_buf())
+    }
+
+    /// `decks_dir` read without creating a default config file
+    pub fn decks_dir_for(config_dir: &Path) -> PathBuf {
+        fs::read_to_string(config_dir.join("config.toml"))
+            .ok()
+            .and_then(|content| toml::from_str::<Self>(&content).ok())
+            .map(|config| config.decks_dir(config_dir))
+            .unwrap_or_else(|| config_dir.to_path_buf())
+    }
+
+    /// Global config with the deck's overrides applied, if the deck is registered
+    pub fn load_for_deck(config_dir: &Path, deck_name: &str) -> Result<Self> {
+        let mut config = Self::load(config_dir)?;
+        if let Ok(deck) = DeckRegistry::load(config_dir)?.get_deck(deck_name) {
+            config.apply_overrides(&deck.overrides);
+        }
+        Ok(config)
+    }
+
+    pub fn apply_overrides(&mut self, overrides: &DeckConfigOverrides) {
+        if let Some(minutes) = overrides.session_timeout_minutes {
+            self.session_timeout_minutes = minutes;
+        }
+        if let Some(enabled) = overrides.enable_biometric {
+            self.enable_biometric = enabled;
+        }
+    }
+
     pub fn save(&self, config_dir: &Path) -> Result<()> {
         let config_path = config_dir.join("config.toml");
         let content = toml::to_string_pretty(self).context("Failed to serialize config")?;
@@ -39,15 +178,158 @@ impl Config {
         Ok(())
     }
 
+    /// Keys accepted by `hc config get/set`
+    pub const KEYS: &'static [&'static str] = &[
+        "deck_path",
+        "vaults_dir",
+        "session_timeout_minutes",
+        "enable_biometric",
+        "non_secret_cards",
+        "ssh_key_lifetime_seconds",
+        "ssh_unload_on_exit",
+        "rotate_salt_on_save",
+    ];
+
+    /// Whether a card may be displayed without `--show` (case-insensitive)
+    pub fn is_non_secret(&self, card_key: &str) -> bool {
+        self.non_secret_cards
+            .iter()
+            .any(|k| k.eq_ignore_ascii_case(card_key))
+    }
+
+    pub fn get(&self, key: &str) -> Result<String> {
+        match canonical_key(key)? {
+            "deck_path" => Ok(self.deck_path.display().to_string()),
+            "vaults_dir" => Ok(self
+                .vaults_dir
+                .as_ref()
+                .map(|dir| dir.display().to_string())
+                .unwrap_or_default()),
+            "session_timeout_minutes" => Ok(self.session_timeout_minutes.to_string()),
+            "enable_biometric" => Ok(self.enable_biometric.to_string()),
+            "non_secret_cards" => Ok(self.non_secret_cards.join(",")),
+            "ssh_key_lifetime_seconds" => Ok(self.ssh_key_lifetime_seconds.to_string()),
+            "ssh_unload_on_exit" => Ok(self.ssh_unload_on_exit.to_string()),
+            "rotate_salt_on_save" => Ok(self.rotate_salt_on_save.to_string()),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        match canonical_key(key)? {
+            "deck_path" => self.deck_path = expand_tilde(value)?,
+            "vaults_dir" => {
+                self.vaults_dir = if value.is_empty() {
+                    None
+                } else {
+                    Some(expand_tilde(value)?)
+                }
+            }
+            "session_timeout_minutes" => {
+                self.session_timeout_minutes = value.parse().with_context(|| {
+                    format!("Invalid value for session_timeout_minutes: '{}'", value)
+                })?
+            }
+            "enable_biometric" => self.enable_biometric = parse_bool(value)?,
+            "non_secret_cards" => {
+                self.non_secret_cards = value
+                    .split(',')
+                    .map(|k| k.trim().to_string())
+                    .filter(|k| !k.is_empty())
+                    .collect()
+            }
+            "ssh_key_lifetime_seconds" => {
+                self.ssh_key_lifetime_seconds = value.parse().with_context(|| {
+                    format!("Invalid value for ssh_key_lifetime_seconds: '{}'", value)
+                })?
+            }
+            "ssh_unload_on_exit" => self.ssh_unload_on_exit = parse_bool(value)?,
+            "rotate_salt_on_save" => self.rotate_salt_on_save = parse_bool(value)?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+
+    /// Default configuration, keeping this config's `deck_path` and
+    /// `vaults_dir` unless `all`. Relative deck paths in the registry resolve
+    /// against `vaults_dir`, so resetting it would move every such deck.
+    pub fn reset(&self, config_dir: &Path, all: bool) -> Self {
+        let mut defaults = Self::default_with_dir(config_dir);
+        if !all {
+            defaults.deck_path = self.deck_path.clone();
+            defaults.vaults_dir = self.vaults_dir.clone();
+        }
+        defaults
+    }
+
+    /// Settings whose value differs between `self` and `other`
+    pub fn changed_keys(&self, other: &Self) -> Vec<&'static str> {
+        let mut changed: Vec<&'static str> = Self::KEYS
+            .iter()
+            .copied()
+            .filter(|key| self.get(key).ok() != other.get(key).ok())
+            .collect();
+        if self.master_password_policy != other.master_password_policy {
+            changed.push("master_password_policy");
+        }
+        changed
+    }
+
     fn default_with_dir(config_dir: &Path) -> Self {
         Self {
             deck_path: config_dir.join("vault.enc"),
+            vaults_dir: None,
             session_timeout_minutes: 60,
             enable_biometric: default_enable_biometric(),
+            non_secret_cards: default_non_secret_cards(),
+            ssh_key_lifetime_seconds: default_ssh_key_lifetime_seconds(),
+            ssh_unload_on_exit: false,
+            rotate_salt_on_save: false,
+            master_password_policy: MasterPasswordPolicy::default(),
         }
     }
 }
 
+fn canonical_key(key: &str) -> Result<&'static str> {
+    let key = match key {
+        "vault_path" => "deck_path",
+        other => other,
+    };
+
+    Config::KEYS
+        .iter()
+        .copied()
+        .find(|k| *k == key)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unknown config key '{}'. Valid keys: {}",
+                key,
+                Config::KEYS.join(", ")
+            )
+        })
+}
+
+fn parse_bool(value: &str) -> Result<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "on" | "yes" | "1" => Ok(true),
+        "false" | "off" | "no" | "0" => Ok(false),
+        _ => anyhow::bail!(
+            "Invalid boolean '{}' (expected true/false or on/off)",
+            value
+        ),
+    }
+}
+
+pub fn expand_tilde(path: &str) -> Result<PathBuf> {
+    let path = PathBuf::from(path);
+    if path.starts_with("~") {
+        let home = dirs::home_dir().context("Failed to get home directory")?;
+        Ok(home.join(path.strip_prefix("~").unwrap()))
+    } else {
+        Ok(path)
+    }
+}
+
 pub fn get_config_dir() -> Result<PathBuf> {
     let home = dirs::home_dir().context("Failed to get home directory")?;
     let config_dir = home.join(".holecard");
@@ -58,3 +340,109 @@ pub fn get_config_dir() -> Result<PathBuf> {
 
     Ok(config_dir)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_set_known_keys() {
+        let mut config = Config::default_with_dir(Path::new("/tmp/hc"));
+
+        config.set("session_timeout_minutes", "15").unwrap();
+        assert_eq!(config.get("session_timeout_minutes").unwrap(), "15");
+
+        config.set("enable_biometric", "off").unwrap();
+        assert_eq!(config.get("enable_biometric").unwrap(), "false");
+
+        config.set("ssh_key_lifetime_seconds", "600").unwrap();
+        assert_eq!(config.ssh_key_lifetime_seconds, 600);
+
+        config.set("vault_path", "/tmp/other.enc").unwrap();
+        assert_eq!(config.get("deck_path").unwrap(), "/tmp/other.enc");
+    }
+
+    #[test]
+    fn test_non_secret_cards() {
+        let mut config = Config::default_with_dir(Path::new("/tmp/hc"));
+        assert!(config.is_non_secret("Username"));
+        assert!(!config.is_non_secret("password"));
+
+        config.set("non_secret_cards", "user, account_id,").unwrap();
+        assert_eq!(config.get("non_secret_cards").unwrap(), "user,account_id");
+        assert!(config.is_non_secret("account_id"));
+        assert!(!config.is_non_secret("username"));
+    }
+
+    #[test]
+    fn test_apply_overrides() {
+        let mut config = Config::default_with_dir(Path::new("/tmp/hc"));
+        config.enable_biometric = true;
+        config.apply_overrides(&DeckConfigOverrides {
+            session_timeout_minutes: Some(15),
+            enable_biometric: None,
+        });
+        assert_eq!(config.session_timeout_minutes, 15);
+        assert!(config.enable_biometric);
+    }
+
+    #[test]
+    fn test_master_password_policy() {
+        let policy = MasterPasswordPolicy::default();
+        assert!(policy
+            .unmet_requirements("correct horse battery")
+            .is_empty());
+        assert_eq!(policy.unmet_requirements("short").len(), 1);
+
+        let strict = MasterPasswordPolicy {
+            min_length: 8,
+            require_uppercase: true,
+            require_lowercase: true,
+            require_digit: true,
+            require_symbol: true,
+        };
+        assert_eq!(
+            strict.unmet_requirements("alllowercase"),
+            vec!["An uppercase letter", "A digit", "A symbol"]
+        );
+        assert!(strict.unmet_requirements("Str0ng!pass").is_empty());
+    }
+
+    #[test]
+    fn test_config_round_trip_with_policy() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default_with_dir(dir.path());
+        config.master_password_policy.require_digit = true;
+        config.save(dir.path()).unwrap();
+
+        let loaded = Config::load(dir.path()).unwrap();
+        assert_eq!(loaded.master_password_policy, config.master_password_policy);
+    }
+
+    #[test]
+    fn test_unknown_key_and_bad_value() {
+        let mut config = Config::default_with_dir(Path::new("/tmp/hc"));
+        assert!(config.get("nope").is_err());
+        assert!(config.set("nope", "1").is_err());
+        assert!(config.set("session_timeout_minutes", "soon").is_err());
+    }
+
+    #[test]
+    fn test_reset_keeps_deck_path_unless_all() {
+        let dir = Path::new("/tmp/hc");
+        let mut config = Config::default_with_dir(dir);
+        config.set("deck_path", "/tmp/custom.enc").unwrap();
+        config.vaults_dir = Some(PathBuf::from("/tmp/decks"));
+        config.set("session_timeout_minutes", "5").unwrap();
+
+        let reset = config.reset(dir, false);
+        assert_eq!(reset.get("deck_path").unwrap(), "/tmp/custom.enc");
+        assert_eq!(reset.vaults_dir, Some(PathBuf::from("/tmp/decks")));
+        assert_eq!(config.changed_keys(&reset), vec!["session_timeout_minutes"]);
+
+        let reset = config.reset(dir, true);
+        assert_eq!(reset.deck_path, dir.join("vault.enc"));
+        assert_eq!(reset.vaults_dir, None);
+        assert!(config.changed_keys(&reset).contains(&"deck_path"));
+    }
+}
@@ -1,13 +1,38 @@
 use anyhow::Result;
+use std::cell::Cell;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::SystemTime;
+use zeroize::Zeroizing;
 
 use crate::cli::input;
 use crate::config::Config;
-use crate::domain::Deck;
+use crate::domain::{CryptoError, CryptoService, Deck, DeckChanges, HcError};
 use crate::infrastructure::{
-    CryptoServiceImpl, DeckStorage, KeyringManager, SessionData, SessionManager,
+    CryptoServiceImpl, DeckLock, DeckStorage, KeyringManager, SessionData, SessionManager,
 };
 
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+
+/// Make every `DeckContext::save` report what it would write instead of
+/// writing (global `--dry-run`).
+pub fn set_dry_run(dry_run: bool) {
+    DRY_RUN.store(dry_run, Ordering::Relaxed);
+}
+
+pub fn is_dry_run() -> bool {
+    DRY_RUN.load(Ordering::Relaxed)
+}
+
+/// Under `--dry-run`, report the file a command would write and return
+/// `true` so the caller skips writing it.
+pub fn skip_write_for_dry_run(path: &Path) -> bool {
+    if is_dry_run() {
+        eprintln!("[dry-run] Would write {}", path.display());
+    }
+    is_dry_run()
+}
+
 pub struct DeckContext {
     pub deck: Deck,
     pub storage: DeckStorage<CryptoServiceImpl>,
@@ -16,46 +41,65 @@ pub struct DeckContext {
     pub config_dir: PathBuf,
     deck_path: PathBuf,
     deck_name: String,
+    /// Modification time of the deck file as last loaded or saved by us
+    loaded_mtime: Cell<Option<SystemTime>>,
+    /// Master password and secret key, kept only when the password was typed
+    /// in this process and `rotate_salt_on_save` is enabled
+    rekey_material: Option<(Zeroizing<String>, Zeroizing<String>)>,
+    /// Deck as loaded, kept under `--dry-run` to summarise skipped saves
+    dry_run_baseline: Option<Deck>,
+}
+
+fn deck_mtime(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Where the master password used for an unlock attempt came from
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PasswordSource {
+    Prompt,
+    /// Typed after biometric auth found nothing cached; cache it once it unlocks the deck
+    PromptToCache,
+    Keyring,
 }
 
 fn resolve_master_password(
     config: &Config,
     keyring: &KeyringManager,
     deck_name: &str,
-) -> Result<String> {
+) -> Result<(String, PasswordSource)> {
     if !config.enable_biometric {
-        return input::prompt_master_password();
+        return Ok((input::prompt_master_password()?, PasswordSource::Prompt));
     }
 
     let biometric = crate::infrastructure::get_biometric_auth();
     if !biometric.is_available() {
-        return input::prompt_master_password();
+        return Ok((input::prompt_master_password()?, PasswordSource::Prompt));
     }
 
-    println!("🔐 Authenticating...");
+    eprintln!("🔐 Authenticating...");
     match biometric.authenticate("Unlock your deck") {
         Ok(true) => {
-            println!("✅ Authentication successful");
+            eprintln!("✅ Authentication successful");
             match keyring.load_master_password(deck_name)? {
                 Some(pwd) => {
-                    println!("🔓 Unlocking deck...");
-                    Ok(pwd)
+                    eprintln!("🔓 Unlocking deck...");
+                    Ok((pwd, PasswordSource::Keyring))
                 }
                 None => {
-                    println!("⚠️  No cached password found. Please enter your master password.");
+                    eprintln!("⚠️  No cached password found. Please enter your master password.");
                     let pwd = input::prompt_master_password()?;
-                    keyring.save_master_password(deck_name, &pwd)?;
-                    Ok(pwd)
+                    Ok((pwd, PasswordSource::PromptToCache))
                 }
             }
         }
         Ok(false) => {
-            println!("⚠️  Authentication failed. Falling back to password.");
-            input::prompt_master_password()
+            eprintln!("⚠️  Authentication failed. Falling back to password.");
+            Ok((input::prompt_master_password()?, PasswordSource::Prompt))
         }
         Err(e) => {
             eprintln!("⚠️  Authentication error: {}. Falling back to password.", e);
-            input::prompt_master_password()
+            Ok((input::prompt_master_password()?, PasswordSource::Prompt))
         }
     }
 }
@@ -68,26 +112,57 @@ impl DeckContext {
         config_dir: &Path,
     ) -> Result<Self> {
         let secret_key = keyring.load_secret_key()?;
-        let config = Config::load(config_dir)?;
+        let config = Config::load_for_deck(config_dir, deck_name)?;
+        let loaded_mtime = deck_mtime(deck_path);
         let crypto = CryptoServiceImpl::new();
         let storage = DeckStorage::new(crypto);
         let session = SessionManager::new(config_dir, deck_name, config.session_timeout_minutes);
 
-        let (deck, session_data) = if let Some(cached) = session.load_session()? {
-            let deck = storage
-                .load_with_cached_key(deck_path, &cached.derived_key)
-                .map_err(|e| anyhow::anyhow!("{}", e))?;
-            (deck, cached)
+        let cached = match session.load_session()? {
+            Some(cached) => match storage.load_with_cached_key(deck_path, &cached.derived_key) {
+                Ok(deck) => {
+                    log::debug!("Unlocked deck '{}' with the cached session", deck_name);
+                    Some((deck, cached))
+                }
+                Err(CryptoError::IncorrectPassword) => {
+                    // The deck was re-keyed since this session was cached.
+                    log::debug!("Cached session for '{}' is stale; clearing it", deck_name);
+                    let _ = session.clear_session();
+                    None
+                }
+                Err(e) => return Err(HcError::from(e).into()),
+            },
+            None => None,
+        };
+
+        let mut rekey_material = None;
+        let (deck, session_data) = if let Some(cached) = cached {
+            cached
         } else {
-            let master_password = resolve_master_password(&config, keyring, deck_name)?;
+            let (master_password, source) = resolve_master_password(&config, keyring, deck_name)?;
 
             let (derived_key, salt) = storage
                 .derive_key_from_deck(deck_path, &master_password, &secret_key)
-                .map_err(|e| anyhow::anyhow!("{}", e))?;
+                .map_err(HcError::from)?;
 
-            let deck = storage
-                .load_with_cached_key(deck_path, &derived_key)
-                .map_err(|e| anyhow::anyhow!("{}", e))?;
+            let deck = match storage.load_with_cached_key(deck_path, &derived_key) {
+                Ok(deck) => deck,
+                Err(CryptoError::IncorrectPassword) => {
+                    // Only a key mismatch means the biometric-cached password is stale;
+                    // read or parse failures leave it in place.
+                    if source == PasswordSource::Keyring {
+                        let _ = keyring.delete_master_password(deck_name);
+                    }
+                    return Err(
+                        anyhow::Error::new(HcError::from(CryptoError::IncorrectPassword))
+                            .context(format!("Failed to unlock deck '{}'", deck_name)),
+                    );
+                }
+                Err(e) => return Err(HcError::from(e).into()),
+            };
+            if source == PasswordSource::PromptToCache {
+                keyring.save_master_password(deck_name, &master_password)?;
+            }
 
             let hand_names: Vec<String> = deck
                 .list_hands()
@@ -96,6 +171,10 @@ impl DeckContext {
                 .collect();
 
             session.save_session(&derived_key, &salt, hand_names.clone())?;
+            if config.rotate_salt_on_save {
+                rekey_material =
+                    Some((Zeroizing::new(master_password), Zeroizing::new(secret_key)));
+            }
             let session_data = SessionData {
                 derived_key,
                 salt,
@@ -104,6 +183,8 @@ impl DeckContext {
             (deck, session_data)
         };
 
+        let dry_run_baseline = is_dry_run().then(|| deck.clone());
+
         Ok(Self {
             deck,
             storage,
@@ -112,10 +193,44 @@ impl DeckContext {
             config_dir: config_dir.to_path_buf(),
             deck_path: deck_path.to_path_buf(),
             deck_name: deck_name.to_string(),
+            loaded_mtime: Cell::new(loaded_mtime),
+            rekey_material,
+            dry_run_baseline,
         })
     }
 
-    pub fn save(&self) -> Result<()> {
+    /// Take the deck's advisory lock, failing if another process changed the
+    /// file since it was loaded (its changes would otherwise be overwritten).
+    pub fn lock(&self) -> Result<DeckLock> {
+        let lock = DeckLock::acquire(&self.deck_path)?;
+        if deck_mtime(&self.deck_path) != self.loaded_mtime.get() {
+            anyhow::bail!(
+                "Deck '{}' was modified by another process since it was loaded. Re-run the command.",
+                self.deck_name
+            );
+        }
+        Ok(lock)
+    }
+
+    pub fn save(&mut self) -> Result<()> {
+        if let Some(baseline) = &self.dry_run_baseline {
+            report_dry_run(&self.deck_name, &self.deck.changes_since(baseline));
+            return Ok(());
+        }
+
+        let _lock = self.lock()?;
+
+        // A fresh salt needs the password; cached-session saves keep the old one
+        // (use `hc passwd` or `hc deck compact --new-salt` to rotate it then).
+        if let Some((password, secret_key)) = &self.rekey_material {
+            let mut salt = [0u8; 16];
+            rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut salt);
+            self.session_data.derived_key = CryptoServiceImpl::new()
+                .derive_key(password, secret_key, &salt)
+                .map_err(HcError::from)?;
+            self.session_data.salt = salt;
+        }
+
         self.storage
             .save_with_cached_key(
                 &self.deck,
@@ -123,7 +238,13 @@ impl DeckContext {
                 &self.session_data.derived_key,
                 &self.session_data.salt,
             )
-            .map_err(|e| anyhow::anyhow!("{}", e))?;
+            .map_err(HcError::from)?;
+        self.loaded_mtime.set(deck_mtime(&self.deck_path));
+        log::debug!(
+            "Saved deck '{}' to {}",
+            self.deck_name,
+            self.deck_path.display()
+        );
 
         let hand_names: Vec<String> = self
             .deck
@@ -146,3 +267,23 @@ impl DeckContext {
         Ok(())
     }
 }
+
+fn report_dry_run(deck_name: &str, changes: &DeckChanges) {
+    if changes.is_empty() {
+        eprintln!(
+            "[dry-run] Would save deck '{}' (no hand changes)",
+            deck_name
+        );
+        return;
+    }
+    eprintln!("[dry-run] Would save deck '{}':", deck_name);
+    for (label, names) in [
+        ("add", &changes.added),
+        ("update", &changes.changed),
+        ("remove", &changes.removed),
+    ] {
+        if !names.is_empty() {
+            eprintln!("  {}: {}", label, names.join(", "));
+        }
+    }
+}
@@ -1,7 +1,87 @@
 use super::error::DeckError;
-use super::hand::Hand;
+use super::hand::{validate_name, Hand};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::str::FromStr;
+
+/// How to resolve a name collision when importing a hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// Keep the existing hand untouched
+    Skip,
+    /// Replace the existing hand with the imported one
+    Overwrite,
+    /// Keep whichever hand has the later `updated_at`
+    NewestWins,
+    /// Union both card maps, preferring the newer hand's values
+    MergeCards,
+}
+
+impl FromStr for MergeStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "skip" => Ok(Self::Skip),
+            "overwrite" => Ok(Self::Overwrite),
+            "newest-wins" => Ok(Self::NewestWins),
+            "merge-cards" => Ok(Self::MergeCards),
+            _ => Err(format!(
+                "Unknown merge strategy '{}' (expected skip, overwrite, newest-wins or merge-cards)",
+                s
+            )),
+        }
+    }
+}
+
+/// Order for listing hands. Timestamps sort oldest first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HandSort {
+    #[default]
+    Name,
+    Created,
+    Updated,
+}
+
+impl FromStr for HandSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "name" => Ok(Self::Name),
+            "created" => Ok(Self::Created),
+            "updated" => Ok(Self::Updated),
+            _ => Err(format!(
+                "Unknown sort '{}' (expected name, created or updated)",
+                s
+            )),
+        }
+    }
+}
+
+/// What happened to a single hand during a merge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeOutcome {
+    Added,
+    Skipped,
+    Overwritten,
+    KeptExisting,
+    Merged,
+}
+
+/// Hand names that differ between two versions of a deck, each sorted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeckChanges {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl DeckChanges {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Deck {
@@ -17,6 +97,7 @@ impl Deck {
     }
 
     pub fn add_hand(&mut self, hand: Hand) -> Result<(), DeckError> {
+        hand.validate()?;
         if self.hands.contains_key(hand.name()) {
             return Err(DeckError::HandAlreadyExists(hand.name().to_string()));
         }
@@ -42,14 +123,111 @@ impl Deck {
             .ok_or_else(|| DeckError::HandNotFound(name.to_string()))
     }
 
+    /// Stored name for `name`: an exact match, else a unique case-insensitive one
+    pub fn find_hand_name(&self, name: &str) -> Option<&str> {
+        if let Some((stored, _)) = self.hands.get_key_value(name) {
+            return Some(stored);
+        }
+
+        let mut matches = self
+            .hands
+            .keys()
+            .filter(|stored| stored.eq_ignore_ascii_case(name));
+        match (matches.next(), matches.next()) {
+            (Some(stored), None) => Some(stored),
+            _ => None,
+        }
+    }
+
+    /// Names of hands whose `key` card equals `value` (or contains it), sorted
+    pub fn find_hands_by_card(&self, key: &str, value: &str, contains: bool) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .hands
+            .values()
+            .filter(|hand| {
+                hand.cards.get(key).is_some_and(|card| {
+                    if contains {
+                        card.contains(value)
+                    } else {
+                        card == value
+                    }
+                })
+            })
+            .map(|hand| hand.name())
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Hand names within a small edit distance of `name`, closest first
+    pub fn similar_hand_names(&self, name: &str) -> Vec<&str> {
+        let name = name.to_lowercase();
+        let max_distance = (name.chars().count() / 3).max(2);
+
+        let mut similar: Vec<(usize, &str)> = self
+            .hands
+            .keys()
+            .map(|stored| {
+                (
+                    edit_distance(&name, &stored.to_lowercase()),
+                    stored.as_str(),
+                )
+            })
+            .filter(|(distance, _)| *distance <= max_distance)
+            .collect();
+        similar.sort();
+        similar.into_iter().map(|(_, stored)| stored).collect()
+    }
+
     pub fn list_hands(&self) -> Vec<&Hand> {
+        self.list_hands_sorted(HandSort::Name)
+    }
+
+    /// Hands in the given order, ties broken by name
+    pub fn list_hands_sorted(&self, sort: HandSort) -> Vec<&Hand> {
         let mut hands: Vec<&Hand> = self.hands.values().collect();
-        hands.sort_by(|a, b| a.name().cmp(b.name()));
+        hands.sort_by(|a, b| {
+            let by_sort = match sort {
+                HandSort::Name => std::cmp::Ordering::Equal,
+                HandSort::Created => a.created_at.cmp(&b.created_at),
+                HandSort::Updated => a.updated_at.cmp(&b.updated_at),
+            };
+            by_sort.then_with(|| a.name().cmp(b.name()))
+        });
         hands
     }
 
+    /// Hands added, removed or modified in `self` relative to `before`
+    pub fn changes_since(&self, before: &Deck) -> DeckChanges {
+        let mut changes = DeckChanges::default();
+        for (name, hand) in &self.hands {
+            match before.hands.get(name) {
+                None => changes.added.push(name.clone()),
+                Some(old)
+                    if old.updated_at != hand.updated_at
+                        || old.cards != hand.cards
+                        || old.notes != hand.notes =>
+                {
+                    changes.changed.push(name.clone())
+                }
+                Some(_) => {}
+            }
+        }
+        changes.removed = before
+            .hands
+            .keys()
+            .filter(|name| !self.hands.contains_key(*name))
+            .cloned()
+            .collect();
+        changes.added.sort();
+        changes.removed.sort();
+        changes.changed.sort();
+        changes
+    }
+
     #[allow(dead_code)]
     pub fn rename_hand(&mut self, old_name: &str, new_name: String) -> Result<(), DeckError> {
+        validate_name(&new_name)?;
         if self.hands.contains_key(&new_name) {
             return Err(DeckError::HandAlreadyExists(new_name));
         }
@@ -59,17 +237,46 @@ impl Deck {
         Ok(())
     }
 
-    pub fn import_hand(&mut self, hand: Hand, overwrite: bool) -> Result<bool, DeckError> {
-        if self.hands.contains_key(hand.name()) {
-            if overwrite {
-                self.hands.insert(hand.name().to_string(), hand);
-                Ok(true)
-            } else {
-                Err(DeckError::HandAlreadyExists(hand.name().to_string()))
+    pub fn merge_hand(&mut self, incoming: Hand, strategy: MergeStrategy) -> MergeOutcome {
+        let Some(existing) = self.hands.get(incoming.name()) else {
+            self.hands.insert(incoming.name().to_string(), incoming);
+            return MergeOutcome::Added;
+        };
+
+        let incoming_is_newer = incoming.updated_at > existing.updated_at;
+
+        match strategy {
+            MergeStrategy::Skip => MergeOutcome::Skipped,
+            MergeStrategy::Overwrite => {
+                self.hands.insert(incoming.name().to_string(), incoming);
+                MergeOutcome::Overwritten
+            }
+            MergeStrategy::NewestWins => {
+                if incoming_is_newer {
+                    self.hands.insert(incoming.name().to_string(), incoming);
+                    MergeOutcome::Overwritten
+                } else {
+                    MergeOutcome::KeptExisting
+                }
+            }
+            MergeStrategy::MergeCards => {
+                let (mut newer, older) = if incoming_is_newer {
+                    (incoming, existing.clone())
+                } else {
+                    (existing.clone(), incoming)
+                };
+
+                for (key, value) in older.cards {
+                    newer.cards.entry(key).or_insert(value);
+                }
+                if newer.notes.is_none() {
+                    newer.notes = older.notes;
+                }
+                newer.created_at = newer.created_at.min(older.created_at);
+
+                self.hands.insert(newer.name().to_string(), newer);
+                MergeOutcome::Merged
             }
-        } else {
-            self.hands.insert(hand.name().to_string(), hand);
-            Ok(false)
         }
     }
 }
@@ -79,3 +286,231 @@ impl Default for Deck {
         Self::new()
     }
 }
+
+/// Levenshtein distance between two strings, by chars
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn hand(name: &str, cards: &[(&str, &str)], age_minutes: i64) -> Hand {
+        let cards = cards
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let mut hand = Hand::new(name.to_string(), cards, None);
+        hand.updated_at -= Duration::minutes(age_minutes);
+        hand
+    }
+
+    #[test]
+    fn test_duplicate_hand() {
+        let mut deck = Deck::new();
+        let mut source = hand("github", &[("password", "secret")], 30);
+        source.notes = Some("work".to_string());
+        source.created_at -= Duration::minutes(30);
+        deck.add_hand(source).unwrap();
+
+        let copy = deck
+            .get_hand("github")
+            .unwrap()
+            .duplicate("github-2".into());
+        assert_eq!(copy.cards, deck.get_hand("github").unwrap().cards);
+        assert_eq!(copy.notes.as_deref(), Some("work"));
+        let original = deck.get_hand("github").unwrap();
+        assert!(copy.created_at > original.created_at);
+        assert!(copy.updated_at > original.updated_at);
+        deck.add_hand(copy).unwrap();
+
+        deck.get_hand_mut("github-2")
+            .unwrap()
+            .cards
+            .insert("password".into(), "changed".into());
+        assert_eq!(deck.get_hand("github").unwrap().cards["password"], "secret");
+
+        let clash = deck
+            .get_hand("github")
+            .unwrap()
+            .duplicate("github-2".into());
+        assert!(matches!(
+            deck.add_hand(clash),
+            Err(DeckError::HandAlreadyExists(name)) if name == "github-2"
+        ));
+    }
+
+    #[test]
+    fn test_changes_since() {
+        let mut before = Deck::new();
+        before.add_hand(hand("kept", &[("k", "v")], 5)).unwrap();
+        before.add_hand(hand("edited", &[("k", "v")], 5)).unwrap();
+        before.add_hand(hand("gone", &[("k", "v")], 5)).unwrap();
+
+        let mut after = before.clone();
+        assert!(after.changes_since(&before).is_empty());
+
+        after.remove_hand("gone").unwrap();
+        after.add_hand(hand("new", &[("k", "v")], 0)).unwrap();
+        after
+            .get_hand_mut("edited")
+            .unwrap()
+            .cards
+            .insert("k".to_string(), "v2".to_string());
+
+        let changes = after.changes_since(&before);
+        assert_eq!(changes.added, vec!["new"]);
+        assert_eq!(changes.removed, vec!["gone"]);
+        assert_eq!(changes.changed, vec!["edited"]);
+    }
+
+    #[test]
+    fn test_merge_adds_new_hand() {
+        let mut deck = Deck::new();
+        let outcome = deck.merge_hand(hand("a", &[("k", "v")], 0), MergeStrategy::Skip);
+        assert_eq!(outcome, MergeOutcome::Added);
+        assert!(deck.get_hand("a").is_ok());
+    }
+
+    #[test]
+    fn test_merge_newest_wins() {
+        let mut deck = Deck::new();
+        deck.add_hand(hand("a", &[("password", "old")], 10))
+            .unwrap();
+
+        let outcome = deck.merge_hand(
+            hand("a", &[("password", "older")], 20),
+            MergeStrategy::NewestWins,
+        );
+        assert_eq!(outcome, MergeOutcome::KeptExisting);
+        assert_eq!(deck.get_hand("a").unwrap().cards["password"], "old");
+
+        let outcome = deck.merge_hand(
+            hand("a", &[("password", "new")], 0),
+            MergeStrategy::NewestWins,
+        );
+        assert_eq!(outcome, MergeOutcome::Overwritten);
+        assert_eq!(deck.get_hand("a").unwrap().cards["password"], "new");
+    }
+
+    #[test]
+    fn test_merge_cards_prefers_newer_values() {
+        let mut deck = Deck::new();
+        deck.add_hand(hand("a", &[("password", "old"), ("username", "me")], 10))
+            .unwrap();
+
+        let outcome = deck.merge_hand(
+            hand("a", &[("password", "new"), ("token", "t")], 0),
+            MergeStrategy::MergeCards,
+        );
+        assert_eq!(outcome, MergeOutcome::Merged);
+
+        let merged = deck.get_hand("a").unwrap();
+        assert_eq!(merged.cards["password"], "new");
+        assert_eq!(merged.cards["username"], "me");
+        assert_eq!(merged.cards["token"], "t");
+    }
+
+    #[test]
+    fn test_add_hand_rejects_newline_in_name() {
+        let mut deck = Deck::new();
+        assert!(matches!(
+            deck.add_hand(hand("two\nlines", &[], 0)),
+            Err(DeckError::InvalidName(_))
+        ));
+        assert!(deck.list_hands().is_empty());
+    }
+
+    #[test]
+    fn test_parse_merge_strategy() {
+        assert_eq!(
+            "newest-wins".parse::<MergeStrategy>().unwrap(),
+            MergeStrategy::NewestWins
+        );
+        assert!("bogus".parse::<MergeStrategy>().is_err());
+    }
+
+    #[test]
+    fn test_find_hand_name_falls_back_to_case_insensitive() {
+        let mut deck = Deck::new();
+        deck.add_hand(hand("github", &[], 0)).unwrap();
+        deck.add_hand(hand("Work", &[], 0)).unwrap();
+        deck.add_hand(hand("work", &[], 0)).unwrap();
+
+        assert_eq!(deck.find_hand_name("github"), Some("github"));
+        assert_eq!(deck.find_hand_name("GitHub"), Some("github"));
+        assert_eq!(deck.find_hand_name("work"), Some("work"));
+        assert_eq!(deck.find_hand_name("WORK"), None);
+        assert_eq!(deck.find_hand_name("gitlab"), None);
+    }
+
+    #[test]
+    fn test_similar_hand_names() {
+        let mut deck = Deck::new();
+        for name in ["github", "gitlab", "aws"] {
+            deck.add_hand(hand(name, &[], 0)).unwrap();
+        }
+
+        assert_eq!(deck.similar_hand_names("githb"), vec!["github", "gitlab"]);
+        assert!(deck.similar_hand_names("slack").is_empty());
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_find_hands_by_card() {
+        let mut deck = Deck::new();
+        deck.add_hand(hand("github", &[("username", "alice")], 0))
+            .unwrap();
+        deck.add_hand(hand("aws", &[("username", "alice@corp")], 0))
+            .unwrap();
+        deck.add_hand(hand("slack", &[("email", "alice")], 0))
+            .unwrap();
+
+        assert_eq!(
+            deck.find_hands_by_card("username", "alice", false),
+            vec!["github"]
+        );
+        assert_eq!(
+            deck.find_hands_by_card("username", "alice", true),
+            vec!["aws", "github"]
+        );
+        assert!(deck.find_hands_by_card("username", "bob", true).is_empty());
+    }
+
+    #[test]
+    fn test_list_hands_sorted_by_updated() {
+        let mut deck = Deck::new();
+        deck.add_hand(hand("a", &[], 5)).unwrap();
+        deck.add_hand(hand("b", &[], 30)).unwrap();
+        deck.add_hand(hand("c", &[], 10)).unwrap();
+
+        let names = |hands: Vec<&Hand>| {
+            hands
+                .iter()
+                .map(|h| h.name().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(deck.list_hands()), vec!["a", "b", "c"]);
+        assert_eq!(
+            names(deck.list_hands_sorted(HandSort::Updated)),
+            vec!["b", "c", "a"]
+        );
+        assert_eq!("created".parse::<HandSort>(), Ok(HandSort::Created));
+        assert!("size".parse::<HandSort>().is_err());
+    }
+}
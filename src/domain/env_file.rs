@@ -0,0 +1,155 @@
+use anyhow::Result;
+use std::str::FromStr;
+
+/// Output format for `hc export`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Password-encrypted JSON, importable with `hc import`
+    #[default]
+    Encrypted,
+    /// Plaintext `export KEY='value'` lines for a shell to source
+    Env,
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "encrypted" => Ok(Self::Encrypted),
+            "env" => Ok(Self::Env),
+            _ => Err(format!(
+                "Unknown export format '{}' (expected encrypted or env)",
+                s
+            )),
+        }
+    }
+}
+
+/// Parse a dotenv-style file into `(KEY, value)` pairs.
+///
+/// Blank lines and `#` comments are skipped, an optional `export ` prefix is
+/// accepted, and values wrapped in matching single or double quotes are unquoted.
+pub fn parse_env_file(content: &str) -> Result<Vec<(String, String)>> {
+    let mut vars = Vec::new();
+
+    for (index, raw_line) in content.lines().enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let (key, value) = line.split_once('=').ok_or_else(|| {
+            anyhow::anyhow!("Line {}: expected KEY=value, got '{}'", index + 1, raw_line)
+        })?;
+
+        let key = key.trim();
+        if key.is_empty() {
+            anyhow::bail!("Line {}: empty variable name", index + 1);
+        }
+
+        vars.push((key.to_string(), unquote(value.trim()).to_string()));
+    }
+
+    Ok(vars)
+}
+
+/// Turn a card key into a usable environment variable name: characters
+/// outside `[A-Za-z0-9_]` become `_`, and a leading digit gets a `_` prefix.
+pub fn sanitize_env_key(key: &str) -> String {
+    let mut sanitized: String = key
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+        sanitized.insert(0, '_');
+    }
+    sanitized
+}
+
+/// Wrap `value` in single quotes for a POSIX shell, escaping embedded quotes as `'\''`.
+pub fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Render `(KEY, value)` pairs as `export KEY='value'` lines, one per pair.
+pub fn format_env_exports(vars: &[(String, String)]) -> String {
+    vars.iter()
+        .map(|(key, value)| format!("export {}={}\n", key, shell_quote(value)))
+        .collect()
+}
+
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if value.len() >= 2 && value.starts_with(quote) && value.ends_with(quote) {
+            return &value[1..value.len() - 1];
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_env_key() {
+        assert_eq!(sanitize_env_key("API_KEY"), "API_KEY");
+        assert_eq!(sanitize_env_key("api key"), "api_key");
+        assert_eq!(sanitize_env_key("db.host-name"), "db_host_name");
+        assert_eq!(sanitize_env_key("2fa"), "_2fa");
+    }
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(shell_quote("plain"), "'plain'");
+        assert_eq!(shell_quote(""), "''");
+        assert_eq!(shell_quote("it's $HOME"), "'it'\\''s $HOME'");
+    }
+
+    #[test]
+    fn test_format_env_exports() {
+        let vars = vec![
+            ("DB_USER".to_string(), "admin".to_string()),
+            ("DB_PASSWORD".to_string(), "p@ss word".to_string()),
+        ];
+        assert_eq!(
+            format_env_exports(&vars),
+            "export DB_USER='admin'\nexport DB_PASSWORD='p@ss word'\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_env_file() {
+        let content = r#"
+# database
+DATABASE_URL=hc://myapp/db_url
+export API_KEY="hc://myapp/api_key"
+LOG_LEVEL='debug'
+EMPTY=
+"#;
+        let vars = parse_env_file(content).unwrap();
+        assert_eq!(
+            vars,
+            vec![
+                ("DATABASE_URL".to_string(), "hc://myapp/db_url".to_string()),
+                ("API_KEY".to_string(), "hc://myapp/api_key".to_string()),
+                ("LOG_LEVEL".to_string(), "debug".to_string()),
+                ("EMPTY".to_string(), String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_env_file_rejects_invalid_lines() {
+        assert!(parse_env_file("NOT_A_PAIR").is_err());
+        assert!(parse_env_file("=value").is_err());
+    }
+}
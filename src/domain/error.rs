@@ -7,6 +7,15 @@ pub enum DeckError {
 
     #[error("Hand '{0}' not found")]
     HandNotFound(String),
+
+    #[error("Card '{0}' not found")]
+    CardNotFound(String),
+
+    #[error("Card '{0}' already exists")]
+    CardAlreadyExists(String),
+
+    #[error("Invalid name {0:?}: control characters are not allowed")]
+    InvalidName(String),
 }
 
 #[derive(Error, Debug)]
@@ -20,6 +29,9 @@ pub enum CryptoError {
     #[error("Decryption failed: incorrect password or corrupted data")]
     DecryptionFailed,
 
+    #[error("Incorrect master password or corrupted deck")]
+    IncorrectPassword,
+
     #[error("Cipher initialization failed: {0}")]
     CipherInitFailed(String),
 
@@ -44,3 +56,89 @@ pub enum ProviderError {
     #[error("Invalid hand name format: {0}")]
     InvalidCardFormat(String),
 }
+
+#[derive(Error, Debug)]
+pub enum ResolveError {
+    #[error("{0}")]
+    InvalidUri(String),
+
+    #[error("{0}")]
+    DeckUnavailable(String),
+
+    #[error("Hand '{0}' not found")]
+    HandNotFound(String),
+
+    #[error("Card '{card}' not found in hand '{hand}'")]
+    CardNotFound { hand: String, card: String },
+}
+
+/// Top-level error carrying the domain error kind through `anyhow`, so the
+/// CLI boundary can map it to a stable exit code.
+#[derive(Error, Debug)]
+pub enum HcError {
+    #[error(transparent)]
+    Deck(#[from] DeckError),
+
+    #[error(transparent)]
+    Crypto(#[from] CryptoError),
+
+    #[error(transparent)]
+    Provider(#[from] ProviderError),
+
+    #[error(transparent)]
+    Resolve(#[from] ResolveError),
+}
+
+impl HcError {
+    pub const EXIT_GENERAL: i32 = 1;
+    // 2 is left to clap for usage errors.
+    pub const EXIT_HAND_NOT_FOUND: i32 = 3;
+    pub const EXIT_CARD_NOT_FOUND: i32 = 4;
+    pub const EXIT_AUTH_FAILED: i32 = 5;
+    pub const EXIT_MALFORMED_URI: i32 = 6;
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            HcError::Deck(DeckError::HandNotFound(_))
+            | HcError::Resolve(ResolveError::HandNotFound(_)) => Self::EXIT_HAND_NOT_FOUND,
+            HcError::Deck(DeckError::CardNotFound(_))
+            | HcError::Resolve(ResolveError::CardNotFound { .. }) => Self::EXIT_CARD_NOT_FOUND,
+            HcError::Crypto(CryptoError::IncorrectPassword | CryptoError::DecryptionFailed)
+            | HcError::Resolve(ResolveError::DeckUnavailable(_)) => Self::EXIT_AUTH_FAILED,
+            HcError::Resolve(ResolveError::InvalidUri(_)) => Self::EXIT_MALFORMED_URI,
+            _ => Self::EXIT_GENERAL,
+        }
+    }
+
+    /// Exit code for an error chain: the first `HcError` found decides,
+    /// anything else is a general failure.
+    pub fn exit_code_for(error: &anyhow::Error) -> i32 {
+        error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<HcError>())
+            .map_or(Self::EXIT_GENERAL, HcError::exit_code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_code_for_error_chain() {
+        let err = anyhow::Error::new(HcError::from(DeckError::HandNotFound("x".into())))
+            .context("Failed to load hand");
+        assert_eq!(HcError::exit_code_for(&err), HcError::EXIT_HAND_NOT_FOUND);
+
+        let err = anyhow::Error::new(HcError::from(CryptoError::IncorrectPassword));
+        assert_eq!(HcError::exit_code_for(&err), HcError::EXIT_AUTH_FAILED);
+
+        let err = anyhow::Error::new(HcError::from(ResolveError::InvalidUri("bad".into())));
+        assert_eq!(HcError::exit_code_for(&err), HcError::EXIT_MALFORMED_URI);
+
+        assert_eq!(
+            HcError::exit_code_for(&anyhow::anyhow!("plain")),
+            HcError::EXIT_GENERAL
+        );
+    }
+}
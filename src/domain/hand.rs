@@ -2,6 +2,32 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::error::DeckError;
+
+/// Maximum number of history records kept per hand
+const MAX_HISTORY: usize = 50;
+/// Maximum number of prior values kept per card when value tracking is on
+const MAX_PRIOR_VALUES: usize = 10;
+
+/// Reject hand names and card keys containing control characters (newlines,
+/// NUL, ...), which break list output, completion and env injection.
+pub fn validate_name(name: &str) -> Result<(), DeckError> {
+    if name.chars().any(char::is_control) {
+        return Err(DeckError::InvalidName(name.to_string()));
+    }
+    Ok(())
+}
+
+/// Substrings that mark a card key as holding a secret
+const SECRET_CARD_HINTS: &[&str] = &[
+    "pass",
+    "secret",
+    "token",
+    "api_key",
+    "apikey",
+    "private_key",
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Hand {
     name: String,
@@ -10,6 +36,34 @@ pub struct Hand {
     pub notes: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<HistoryRecord>,
+    /// Opt-in: keep replaced card values in `prior_values`
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub track_values: bool,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub prior_values: HashMap<String, Vec<PriorValue>>,
+}
+
+/// A card value that was replaced or removed, kept when `track_values` is on
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriorValue {
+    pub value: String,
+    pub replaced_at: DateTime<Utc>,
+}
+
+/// A single edit to a hand. Records card keys only, never values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryRecord {
+    pub timestamp: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub updated: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub notes_changed: bool,
 }
 
 impl Hand {
@@ -21,19 +75,80 @@ impl Hand {
             notes,
             created_at: now,
             updated_at: now,
+            history: Vec::new(),
+            track_values: false,
+            prior_values: HashMap::new(),
         }
     }
 
+    /// Clone cards and notes into a new hand with fresh timestamps
+    pub fn duplicate(&self, name: String) -> Self {
+        Self::new(name, self.cards.clone(), self.notes.clone())
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
 
+    /// Check the hand name and every card key with [`validate_name`]
+    pub fn validate(&self) -> Result<(), DeckError> {
+        validate_name(&self.name)?;
+        self.cards.keys().try_for_each(|key| validate_name(key))
+    }
+
     #[allow(dead_code)]
     pub(crate) fn set_name(&mut self, name: String) {
         self.name = name;
         self.updated_at = Utc::now();
     }
 
+    /// Rename a card key, keeping its value
+    pub fn rename_card(&mut self, old_key: &str, new_key: &str) -> Result<(), DeckError> {
+        validate_name(new_key)?;
+        if self.cards.contains_key(new_key) {
+            return Err(DeckError::CardAlreadyExists(new_key.to_string()));
+        }
+        let value = self
+            .cards
+            .remove(old_key)
+            .ok_or_else(|| DeckError::CardNotFound(old_key.to_string()))?;
+        self.cards.insert(new_key.to_string(), value);
+        if let Some(prior) = self.prior_values.remove(old_key) {
+            self.prior_values.insert(new_key.to_string(), prior);
+        }
+        Ok(())
+    }
+
+    /// Turn off value tracking and discard every stored prior value
+    pub fn stop_tracking_values(&mut self) {
+        self.track_values = false;
+        self.prior_values.clear();
+    }
+
+    /// The card a field-less reference points at: `password` if present,
+    /// otherwise the alphabetically first card.
+    pub fn default_card(&self) -> Option<(&str, &String)> {
+        self.cards
+            .get_key_value("password")
+            .or_else(|| self.cards.iter().min_by(|a, b| a.0.cmp(b.0)))
+            .map(|(key, value)| (key.as_str(), value))
+    }
+
+    /// Card keys that look like secrets (password, token, API key...), sorted
+    pub fn secret_card_keys(&self) -> Vec<&str> {
+        let mut keys: Vec<&str> = self
+            .cards
+            .keys()
+            .map(String::as_str)
+            .filter(|key| {
+                let key = key.to_ascii_lowercase();
+                SECRET_CARD_HINTS.iter().any(|hint| key.contains(hint))
+            })
+            .collect();
+        keys.sort();
+        keys
+    }
+
     pub fn update_notes(&mut self, notes: Option<String>) {
         self.notes = notes;
         self.updated_at = Utc::now();
@@ -42,4 +157,235 @@ impl Hand {
     pub fn touch(&mut self) {
         self.updated_at = Utc::now();
     }
+
+    /// Append a history record describing changes since `previous`.
+    /// Does nothing if cards and notes are unchanged.
+    pub fn record_changes(&mut self, previous: &Hand) {
+        let mut added: Vec<String> = Vec::new();
+        let mut updated: Vec<String> = Vec::new();
+        for (key, value) in &self.cards {
+            match previous.cards.get(key) {
+                None => added.push(key.clone()),
+                Some(old) if old != value => updated.push(key.clone()),
+                Some(_) => {}
+            }
+        }
+        let mut removed: Vec<String> = previous
+            .cards
+            .keys()
+            .filter(|key| !self.cards.contains_key(*key))
+            .cloned()
+            .collect();
+        let notes_changed = self.notes != previous.notes;
+
+        if added.is_empty() && updated.is_empty() && removed.is_empty() && !notes_changed {
+            return;
+        }
+
+        added.sort();
+        updated.sort();
+        removed.sort();
+
+        let now = Utc::now();
+        if self.track_values {
+            for key in updated.iter().chain(&removed) {
+                let prior = self.prior_values.entry(key.clone()).or_default();
+                prior.push(PriorValue {
+                    value: previous.cards[key].clone(),
+                    replaced_at: now,
+                });
+                if prior.len() > MAX_PRIOR_VALUES {
+                    let excess = prior.len() - MAX_PRIOR_VALUES;
+                    prior.drain(..excess);
+                }
+            }
+        }
+
+        self.history.push(HistoryRecord {
+            timestamp: now,
+            added,
+            updated,
+            removed,
+            notes_changed,
+        });
+
+        if self.history.len() > MAX_HISTORY {
+            let excess = self.history.len() - MAX_HISTORY;
+            self.history.drain(..excess);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_hand() -> Hand {
+        let mut cards = HashMap::new();
+        cards.insert("username".to_string(), "john".to_string());
+        cards.insert("password".to_string(), "secret".to_string());
+        Hand::new("test".to_string(), cards, None)
+    }
+
+    #[test]
+    fn test_default_card_prefers_password() {
+        let hand = create_test_hand();
+        assert_eq!(
+            hand.default_card(),
+            Some(("password", &"secret".to_string()))
+        );
+
+        let mut cards = HashMap::new();
+        cards.insert("token".to_string(), "t".to_string());
+        cards.insert("api_key".to_string(), "k".to_string());
+        let hand = Hand::new("svc".to_string(), cards, None);
+        assert_eq!(hand.default_card(), Some(("api_key", &"k".to_string())));
+
+        let empty = Hand::new("empty".to_string(), HashMap::new(), None);
+        assert_eq!(empty.default_card(), None);
+    }
+
+    #[test]
+    fn test_secret_card_keys() {
+        let mut hand = create_test_hand();
+        hand.cards.insert("API_KEY".to_string(), "k".to_string());
+        hand.cards
+            .insert("github_token".to_string(), "t".to_string());
+        hand.cards
+            .insert("public_key".to_string(), "ssh-ed25519".to_string());
+        assert_eq!(
+            hand.secret_card_keys(),
+            vec!["API_KEY", "github_token", "password"]
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_control_characters() {
+        assert!(create_test_hand().validate().is_ok());
+
+        let hand = Hand::new("bad\nname".to_string(), HashMap::new(), None);
+        assert!(matches!(hand.validate(), Err(DeckError::InvalidName(_))));
+
+        let mut hand = create_test_hand();
+        hand.cards.insert("nul\0key".to_string(), "v".to_string());
+        assert!(hand.validate().is_err());
+
+        // Spaces are fine in names; only control characters are rejected
+        assert!(validate_name("my server").is_ok());
+        assert!(create_test_hand()
+            .rename_card("username", "user\tname")
+            .is_err());
+    }
+
+    #[test]
+    fn test_rename_card() {
+        let mut hand = create_test_hand();
+        hand.rename_card("username", "login").unwrap();
+        assert_eq!(hand.cards.get("login"), Some(&"john".to_string()));
+        assert!(!hand.cards.contains_key("username"));
+
+        assert!(matches!(
+            hand.rename_card("missing", "other"),
+            Err(DeckError::CardNotFound(_))
+        ));
+        assert!(matches!(
+            hand.rename_card("login", "password"),
+            Err(DeckError::CardAlreadyExists(_))
+        ));
+        assert_eq!(hand.cards.get("password"), Some(&"secret".to_string()));
+    }
+
+    #[test]
+    fn test_record_changes() {
+        let mut hand = create_test_hand();
+        let previous = hand.clone();
+
+        hand.cards
+            .insert("password".to_string(), "rotated".to_string());
+        hand.cards.insert("token".to_string(), "abc".to_string());
+        hand.cards.remove("username");
+        hand.record_changes(&previous);
+
+        assert_eq!(hand.history.len(), 1);
+        let record = &hand.history[0];
+        assert_eq!(record.added, vec!["token"]);
+        assert_eq!(record.updated, vec!["password"]);
+        assert_eq!(record.removed, vec!["username"]);
+        assert!(!record.notes_changed);
+    }
+
+    #[test]
+    fn test_record_changes_skips_noop() {
+        let mut hand = create_test_hand();
+        let previous = hand.clone();
+        hand.record_changes(&previous);
+        assert!(hand.history.is_empty());
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let mut hand = create_test_hand();
+        for i in 0..(MAX_HISTORY + 5) {
+            let previous = hand.clone();
+            hand.cards.insert("password".to_string(), i.to_string());
+            hand.record_changes(&previous);
+        }
+        assert_eq!(hand.history.len(), MAX_HISTORY);
+    }
+
+    #[test]
+    fn test_deserialize_without_history() {
+        let json = r#"{
+            "name": "legacy",
+            "cards": {"password": "secret"},
+            "notes": null,
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        }"#;
+        let hand: Hand = serde_json::from_str(json).unwrap();
+        assert!(hand.history.is_empty());
+    }
+
+    #[test]
+    fn test_prior_values_only_when_tracked() {
+        let mut hand = create_test_hand();
+        let previous = hand.clone();
+        hand.cards.insert("password".to_string(), "v2".to_string());
+        hand.record_changes(&previous);
+        assert!(hand.prior_values.is_empty());
+
+        hand.track_values = true;
+        let old = hand.cards["password"].clone();
+        for i in 0..(MAX_PRIOR_VALUES + 2) {
+            let previous = hand.clone();
+            hand.cards
+                .insert("password".to_string(), format!("v{}", i + 3));
+            hand.record_changes(&previous);
+        }
+
+        let prior = &hand.prior_values["password"];
+        assert_eq!(prior.len(), MAX_PRIOR_VALUES);
+        assert_ne!(prior[0].value, old);
+        assert_eq!(
+            prior.last().unwrap().value,
+            format!("v{}", MAX_PRIOR_VALUES + 3)
+        );
+    }
+
+    #[test]
+    fn test_rename_card_moves_prior_values() {
+        let mut hand = create_test_hand();
+        hand.track_values = true;
+        let previous = hand.clone();
+        hand.cards.insert("password".to_string(), "v2".to_string());
+        hand.record_changes(&previous);
+
+        hand.rename_card("password", "passphrase").unwrap();
+        assert!(!hand.prior_values.contains_key("password"));
+        assert_eq!(hand.prior_values["passphrase"].len(), 1);
+
+        hand.stop_tracking_values();
+        assert!(!hand.track_values);
+        assert!(hand.prior_values.is_empty());
+    }
 }
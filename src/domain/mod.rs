@@ -1,10 +1,13 @@
 pub mod crypto;
 pub mod deck;
+pub mod env_file;
 pub mod error;
+pub mod glob;
 pub mod hand;
 pub mod password_gen;
 pub mod provider;
 pub mod providers;
+pub mod recovery;
 pub mod secret_resolver;
 pub mod ssh_key;
 pub mod template;
@@ -12,12 +15,16 @@ pub mod totp;
 pub mod uri;
 
 pub use crypto::CryptoService;
-pub use deck::Deck;
-pub use error::CryptoError;
-pub use hand::Hand;
-pub use password_gen::PasswordService;
-pub use provider::{card_to_secret_name, ProviderConfig};
+pub use deck::{Deck, DeckChanges, HandSort, MergeOutcome, MergeStrategy};
+pub use env_file::{format_env_exports, parse_env_file, sanitize_env_key, ExportFormat};
+pub use error::{CryptoError, HcError};
+pub use glob::glob_match;
+pub use hand::{validate_name, Hand};
+pub use password_gen::{PasswordService, PasswordStrength};
+pub use provider::{card_to_secret_name, ProviderConfig, SyncPlan};
 pub use secret_resolver::SecretResolver;
-pub use ssh_key::{find_hand_by_name_or_alias, validate_private_key};
+pub use ssh_key::{
+    find_hand_by_name_or_alias, update_ssh_cards, validate_private_key, SshAuthMethod,
+};
 pub use template::TemplateEngine;
-pub use totp::TotpService;
+pub use totp::{TotpAlgorithm, TotpParams, TotpService};
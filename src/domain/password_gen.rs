@@ -172,6 +172,33 @@ impl PassphraseOptions {
     }
 }
 
+/// A zxcvbn estimate of how guessable a password is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordStrength {
+    /// 0 (too guessable) to 4 (very unguessable)
+    pub score: u8,
+    /// Time to crack offline against a slow hash, e.g. "3 days"
+    pub crack_time: String,
+    /// What makes the password guessable, if anything specific
+    pub warning: Option<String>,
+}
+
+impl PasswordStrength {
+    pub fn is_weak(&self) -> bool {
+        self.score < 3
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self.score {
+            0 => "very weak",
+            1 => "weak",
+            2 => "fair",
+            3 => "strong",
+            _ => "very strong",
+        }
+    }
+}
+
 pub struct PasswordService;
 
 impl PasswordService {
@@ -269,6 +296,22 @@ impl PasswordService {
         Ok(words.join("-"))
     }
 
+    /// Estimate password strength with zxcvbn
+    pub fn score(password: &str) -> PasswordStrength {
+        let estimate = zxcvbn::zxcvbn(password, &[]);
+        PasswordStrength {
+            score: u8::from(estimate.score()),
+            crack_time: estimate
+                .crack_times()
+                .offline_slow_hashing_1e4_per_second()
+                .to_string(),
+            warning: estimate
+                .feedback()
+                .and_then(|feedback| feedback.warning())
+                .map(|warning| warning.to_string()),
+        }
+    }
+
     fn build_charset(options: &PasswordOptions) -> Result<Vec<char>> {
         let mut charset = Vec::new();
 
@@ -304,6 +347,18 @@ fn random_index(max: usize) -> usize {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_score() {
+        let weak = PasswordService::score("password1");
+        assert!(weak.is_weak());
+        assert!(weak.warning.is_some());
+
+        let generated = PasswordService::generate_password(&PasswordOptions::default()).unwrap();
+        let strong = PasswordService::score(&generated);
+        assert_eq!(strong.score, 4);
+        assert_eq!(strong.label(), "very strong");
+    }
+
     #[test]
     fn test_generate_password_default() {
         let options = PasswordOptions::default();
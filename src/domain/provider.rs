@@ -12,6 +12,12 @@ pub struct ProviderConfig {
 
 /// Provider trait for secret management services
 pub trait Provider: Send + Sync {
+    /// Provider type identifier (e.g. "github")
+    fn provider_type(&self) -> &str;
+
+    /// User-chosen ID of this provider configuration
+    fn provider_id(&self) -> &str;
+
     /// Push a single secret to the provider
     fn push_secret(&self, key: &str, value: &str) -> Result<()>;
 
@@ -20,36 +26,93 @@ pub trait Provider: Send + Sync {
 
     /// Delete a secret from the provider
     fn delete_secret(&self, key: &str) -> Result<()>;
+
+    /// Check a secret name against the provider's naming rules before pushing
+    fn validate_secret_name(&self, name: &str) -> Result<()>;
 }
 
 /// Convert field name to secret name (snake_case/camelCase -> UPPER_SNAKE_CASE)
+///
+/// Word boundaries are inserted at lowercase/digit -> uppercase, letter -> digit,
+/// and at the end of an uppercase run followed by a lowercase letter
+/// (`HTTPServer` -> `HTTP_SERVER`).
 pub fn card_to_secret_name(field_name: &str) -> String {
+    let chars: Vec<char> = field_name.chars().collect();
     let mut result = String::new();
-    let mut prev_lower = false;
 
-    for (i, ch) in field_name.chars().enumerate() {
+    for (i, &ch) in chars.iter().enumerate() {
         if ch == '_' {
             result.push('_');
-            prev_lower = false;
-        } else if ch.is_uppercase() {
-            if i > 0 && prev_lower {
+            continue;
+        }
+
+        if i > 0 && chars[i - 1] != '_' {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+
+            let boundary = (ch.is_uppercase() && (prev.is_lowercase() || prev.is_ascii_digit()))
+                || (ch.is_uppercase() && prev.is_uppercase() && next_is_lower)
+                || (ch.is_ascii_digit() && prev.is_alphabetic());
+
+            if boundary {
                 result.push('_');
             }
-            result.push(ch);
-            prev_lower = false;
-        } else {
-            result.push(ch.to_ascii_uppercase());
-            prev_lower = true;
         }
+
+        result.push(ch.to_ascii_uppercase());
     }
 
     result
 }
 
+/// Changes needed to make a provider's secrets match a hand's cards
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SyncPlan {
+    /// Secrets missing from the provider
+    pub create: Vec<String>,
+    /// Secrets present on both sides (providers cannot reveal values, so these are always re-pushed)
+    pub update: Vec<String>,
+    /// Provider secrets absent from the hand
+    pub delete: Vec<String>,
+}
+
+impl SyncPlan {
+    pub fn new(local: &[String], remote: &[String]) -> Self {
+        let mut plan = Self::default();
+        for name in local {
+            if remote.contains(name) {
+                plan.update.push(name.clone());
+            } else {
+                plan.create.push(name.clone());
+            }
+        }
+        plan.delete = remote
+            .iter()
+            .filter(|name| !local.contains(name))
+            .cloned()
+            .collect();
+        plan.create.sort();
+        plan.update.sort();
+        plan.delete.sort();
+        plan
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_sync_plan() {
+        let local = vec!["API_KEY".to_string(), "DB_URL".to_string()];
+        let remote = vec!["OLD_TOKEN".to_string(), "DB_URL".to_string()];
+
+        let plan = SyncPlan::new(&local, &remote);
+        assert_eq!(plan.create, vec!["API_KEY"]);
+        assert_eq!(plan.update, vec!["DB_URL"]);
+        assert_eq!(plan.delete, vec!["OLD_TOKEN"]);
+    }
+
     #[test]
     fn test_card_to_secret_name() {
         assert_eq!(card_to_secret_name("db_url"), "DB_URL");
@@ -57,4 +120,17 @@ mod tests {
         assert_eq!(card_to_secret_name("DATABASE_URL"), "DATABASE_URL");
         assert_eq!(card_to_secret_name("mySecretValue"), "MY_SECRET_VALUE");
     }
+
+    #[test]
+    fn test_card_to_secret_name_acronyms_and_digits() {
+        assert_eq!(card_to_secret_name("HTTPServer"), "HTTP_SERVER");
+        assert_eq!(
+            card_to_secret_name("parseHTTPResponse"),
+            "PARSE_HTTP_RESPONSE"
+        );
+        assert_eq!(card_to_secret_name("v2Token"), "V_2_TOKEN");
+        assert_eq!(card_to_secret_name("sha256"), "SHA_256");
+        assert_eq!(card_to_secret_name("API"), "API");
+        assert_eq!(card_to_secret_name("key_2"), "KEY_2");
+    }
 }
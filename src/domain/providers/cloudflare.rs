@@ -3,11 +3,15 @@ use anyhow::{anyhow, Context, Result};
 use reqwest::blocking::{Client, RequestBuilder};
 use serde::{Deserialize, Serialize};
 
+const CLOUDFLARE_API: &str = "https://api.cloudflare.com/client/v4";
+
 /// Cloudflare Workers Secrets Provider
 pub struct CloudflareProvider {
+    provider_id: String,
     account_id: String,
     worker_name: String,
     token: String,
+    api_base: String,
     client: Client,
 }
 
@@ -23,6 +27,25 @@ struct SecretPayload {
 struct SecretsListResponse {
     result: Vec<SecretInfo>,
     success: bool,
+    #[serde(default)]
+    result_info: Option<ResultInfo>,
+}
+
+/// Pagination details on Cloudflare list responses
+#[derive(Deserialize)]
+struct ResultInfo {
+    #[serde(default)]
+    cursor: Option<String>,
+}
+
+impl SecretsListResponse {
+    /// Cursor for the next page; Cloudflare sends an empty one on the last page
+    fn next_cursor(&self) -> Option<String> {
+        self.result_info
+            .as_ref()
+            .and_then(|info| info.cursor.clone())
+            .filter(|cursor| !cursor.is_empty())
+    }
 }
 
 #[derive(Deserialize)]
@@ -42,15 +65,28 @@ struct ApiError {
 }
 
 impl CloudflareProvider {
-    pub fn new(account_id: String, worker_name: String, token: String) -> Self {
+    pub fn new(
+        provider_id: String,
+        account_id: String,
+        worker_name: String,
+        token: String,
+    ) -> Self {
         Self {
+            provider_id,
             account_id,
             worker_name,
             token,
+            api_base: CLOUDFLARE_API.to_string(),
             client: Client::new(),
         }
     }
 
+    #[cfg(test)]
+    fn with_api_base(mut self, api_base: &str) -> Self {
+        self.api_base = api_base.to_string();
+        self
+    }
+
     fn with_cloudflare_headers(&self, builder: RequestBuilder) -> RequestBuilder {
         builder
             .header("Authorization", format!("Bearer {}", self.token))
@@ -59,8 +95,8 @@ impl CloudflareProvider {
 
     fn secrets_url(&self) -> String {
         format!(
-            "https://api.cloudflare.com/client/v4/accounts/{}/workers/scripts/{}/secrets",
-            self.account_id, self.worker_name
+            "{}/accounts/{}/workers/scripts/{}/secrets",
+            self.api_base, self.account_id, self.worker_name
         )
     }
 
@@ -73,7 +109,34 @@ impl CloudflareProvider {
     }
 }
 
+/// Cloudflare Workers expose secrets as bindings, so names must be valid
+/// JavaScript identifiers: `[A-Za-z_$][A-Za-z0-9_$]*`.
+fn validate_cloudflare_secret_name(name: &str) -> Result<()> {
+    let mut chars = name.chars();
+    let valid_start = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$');
+    let valid_rest = chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+
+    if !valid_start || !valid_rest {
+        return Err(anyhow!(
+            "Invalid Cloudflare secret name '{}': must match [A-Za-z_$][A-Za-z0-9_$]*",
+            name
+        ));
+    }
+
+    Ok(())
+}
+
 impl Provider for CloudflareProvider {
+    fn provider_type(&self) -> &str {
+        "cloudflare"
+    }
+
+    fn provider_id(&self) -> &str {
+        &self.provider_id
+    }
+
     fn push_secret(&self, key: &str, value: &str) -> Result<()> {
         let payload = SecretPayload {
             name: key.to_string(),
@@ -85,6 +148,7 @@ impl Provider for CloudflareProvider {
             .with_cloudflare_headers(self.client.put(self.secrets_url()))
             .json(&payload)
             .send()
+            .inspect(super::log_response)
             .context("Failed to push secret to Cloudflare")?;
 
         if !response.status().is_success() {
@@ -102,28 +166,39 @@ impl Provider for CloudflareProvider {
     }
 
     fn list_secrets(&self) -> Result<Vec<String>> {
-        let response = self
-            .with_cloudflare_headers(self.client.get(self.secrets_url()))
-            .send()
-            .context("Failed to list secrets from Cloudflare")?;
+        super::collect_pages(|cursor| {
+            let mut request = self.client.get(self.secrets_url());
+            if let Some(cursor) = cursor {
+                request = request.query(&[("cursor", cursor)]);
+            }
+            let response = self
+                .with_cloudflare_headers(request)
+                .send()
+                .inspect(super::log_response)
+                .context("Failed to list secrets from Cloudflare")?;
 
-        if !response.status().is_success() {
-            return Err(anyhow!(
-                "Cloudflare API error: {} - {}",
-                response.status(),
-                response.text().unwrap_or_default()
-            ));
-        }
+            if !response.status().is_success() {
+                return Err(anyhow!(
+                    "Cloudflare API error: {} - {}",
+                    response.status(),
+                    response.text().unwrap_or_default()
+                ));
+            }
 
-        let secrets_list: SecretsListResponse = response
-            .json()
-            .context("Failed to parse secrets list response")?;
+            let secrets_list: SecretsListResponse = response
+                .json()
+                .context("Failed to parse secrets list response")?;
 
-        if !secrets_list.success {
-            return Err(anyhow!("Failed to list secrets"));
-        }
+            if !secrets_list.success {
+                return Err(anyhow!("Failed to list secrets"));
+            }
 
-        Ok(secrets_list.result.into_iter().map(|s| s.name).collect())
+            let next = secrets_list.next_cursor();
+            Ok(super::Page {
+                names: secrets_list.result.into_iter().map(|s| s.name).collect(),
+                next,
+            })
+        })
     }
 
     fn delete_secret(&self, key: &str) -> Result<()> {
@@ -132,6 +207,7 @@ impl Provider for CloudflareProvider {
         let response = self
             .with_cloudflare_headers(self.client.delete(&url))
             .send()
+            .inspect(super::log_response)
             .context("Failed to delete secret from Cloudflare")?;
 
         if !response.status().is_success() {
@@ -148,4 +224,60 @@ impl Provider for CloudflareProvider {
 
         Self::check_api_response(api_response)
     }
+
+    fn validate_secret_name(&self, name: &str) -> Result<()> {
+        validate_cloudflare_secret_name(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_server::{serve, Canned};
+    use super::*;
+
+    #[test]
+    fn test_list_secrets_follows_cursor() {
+        let (base, server) = serve(|_| {
+            vec![
+                Canned {
+                    headers: Vec::new(),
+                    body: r#"{"success":true,"result":[{"name":"A"},{"name":"B"}],"result_info":{"cursor":"next-1"}}"#.into(),
+                },
+                Canned {
+                    headers: Vec::new(),
+                    body: r#"{"success":true,"result":[{"name":"C"}],"result_info":{"cursor":""}}"#.into(),
+                },
+            ]
+        });
+
+        let provider =
+            CloudflareProvider::new("cf".into(), "acct".into(), "worker".into(), "token".into())
+                .with_api_base(&base);
+        assert_eq!(provider.list_secrets().unwrap(), vec!["A", "B", "C"]);
+        assert_eq!(
+            server.join().unwrap(),
+            vec![
+                "/accounts/acct/workers/scripts/worker/secrets",
+                "/accounts/acct/workers/scripts/worker/secrets?cursor=next-1",
+            ]
+        );
+    }
+
+    fn parse(json: &str) -> SecretsListResponse {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_next_cursor() {
+        let first = parse(
+            r#"{"success":true,"result":[{"name":"A"}],"result_info":{"cursor":"abc","count":1}}"#,
+        );
+        assert_eq!(first.next_cursor().as_deref(), Some("abc"));
+
+        let last = parse(r#"{"success":true,"result":[{"name":"B"}],"result_info":{"cursor":""}}"#);
+        assert_eq!(last.next_cursor(), None);
+
+        let unpaginated = parse(r#"{"success":true,"result":[]}"#);
+        assert_eq!(unpaginated.next_cursor(), None);
+    }
 }
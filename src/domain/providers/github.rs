@@ -4,10 +4,17 @@ use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use reqwest::blocking::{Client, RequestBuilder, Response};
 use serde::{Deserialize, Serialize};
 
+const GITHUB_API: &str = "https://api.github.com";
+
+/// Largest page size the GitHub secrets listing accepts
+const SECRETS_PER_PAGE: u32 = 100;
+
 /// GitHub Actions Secrets Provider
 pub struct GitHubProvider {
+    provider_id: String,
     repo: String,
     token: String,
+    api_base: String,
     client: Client,
 }
 
@@ -34,14 +41,22 @@ struct SecretInfo {
 }
 
 impl GitHubProvider {
-    pub fn new(repo: String, token: String) -> Self {
+    pub fn new(provider_id: String, repo: String, token: String) -> Self {
         Self {
+            provider_id,
             repo,
             token,
+            api_base: GITHUB_API.to_string(),
             client: Client::new(),
         }
     }
 
+    #[cfg(test)]
+    fn with_api_base(mut self, api_base: &str) -> Self {
+        self.api_base = api_base.to_string();
+        self
+    }
+
     fn with_github_headers(&self, builder: RequestBuilder) -> RequestBuilder {
         builder
             .header("Authorization", format!("Bearer {}", self.token))
@@ -63,13 +78,14 @@ impl GitHubProvider {
 
     fn get_public_key(&self) -> Result<PublicKey> {
         let url = format!(
-            "https://api.github.com/repos/{}/actions/secrets/public-key",
-            self.repo
+            "{}/repos/{}/actions/secrets/public-key",
+            self.api_base, self.repo
         );
 
         let response = self
             .with_github_headers(self.client.get(&url))
             .send()
+            .inspect(super::log_response)
             .context("Failed to fetch GitHub public key")?;
 
         Self::check_response(response)?
@@ -97,14 +113,65 @@ impl GitHubProvider {
     }
 }
 
+/// The `rel="next"` URL from a GitHub `Link` header, e.g.
+/// `<https://api.github.com/...&page=2>; rel="next", <...>; rel="last"`.
+fn next_page_url(link: &str) -> Option<String> {
+    link.split(',').find_map(|part| {
+        let (url, params) = part.split_once(';')?;
+        params
+            .split(';')
+            .any(|param| param.trim() == "rel=\"next\"")
+            .then(|| {
+                url.trim()
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string()
+            })
+    })
+}
+
+/// GitHub Actions secret names must match `[A-Z_][A-Z0-9_]*` and must not
+/// start with the reserved `GITHUB_` prefix.
+fn validate_github_secret_name(name: &str) -> Result<()> {
+    let mut chars = name.chars();
+    let valid_start = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_uppercase() || c == '_');
+    let valid_rest = chars.all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+
+    if !valid_start || !valid_rest {
+        return Err(anyhow!(
+            "Invalid GitHub secret name '{}': must match [A-Z_][A-Z0-9_]*",
+            name
+        ));
+    }
+
+    if name.starts_with("GITHUB_") {
+        return Err(anyhow!(
+            "Invalid GitHub secret name '{}': names must not start with GITHUB_",
+            name
+        ));
+    }
+
+    Ok(())
+}
+
 impl Provider for GitHubProvider {
+    fn provider_type(&self) -> &str {
+        "github"
+    }
+
+    fn provider_id(&self) -> &str {
+        &self.provider_id
+    }
+
     fn push_secret(&self, key: &str, value: &str) -> Result<()> {
         let public_key = self.get_public_key()?;
         let encrypted_value = self.encrypt_secret(value, &public_key.key)?;
 
         let url = format!(
-            "https://api.github.com/repos/{}/actions/secrets/{}",
-            self.repo, key
+            "{}/repos/{}/actions/secrets/{}",
+            self.api_base, self.repo, key
         );
 
         let payload = SecretPayload {
@@ -116,6 +183,7 @@ impl Provider for GitHubProvider {
             .with_github_headers(self.client.put(&url))
             .json(&payload)
             .send()
+            .inspect(super::log_response)
             .context("Failed to push secret to GitHub")?;
 
         Self::check_response(response)?;
@@ -123,32 +191,122 @@ impl Provider for GitHubProvider {
     }
 
     fn list_secrets(&self) -> Result<Vec<String>> {
-        let url = format!("https://api.github.com/repos/{}/actions/secrets", self.repo);
+        let first = format!(
+            "{}/repos/{}/actions/secrets?per_page={}",
+            self.api_base, self.repo, SECRETS_PER_PAGE
+        );
 
-        let response = self
-            .with_github_headers(self.client.get(&url))
-            .send()
-            .context("Failed to list secrets from GitHub")?;
+        super::collect_pages(|next| {
+            let url = next.unwrap_or(&first);
+            let response = self
+                .with_github_headers(self.client.get(url))
+                .send()
+                .inspect(super::log_response)
+                .context("Failed to list secrets from GitHub")?;
+            let response = Self::check_response(response)?;
 
-        let secrets_list: SecretsList = Self::check_response(response)?
-            .json()
-            .context("Failed to parse secrets list response")?;
+            let next = response
+                .headers()
+                .get(reqwest::header::LINK)
+                .and_then(|value| value.to_str().ok())
+                .and_then(next_page_url);
+            let secrets_list: SecretsList = response
+                .json()
+                .context("Failed to parse secrets list response")?;
 
-        Ok(secrets_list.secrets.into_iter().map(|s| s.name).collect())
+            Ok(super::Page {
+                names: secrets_list.secrets.into_iter().map(|s| s.name).collect(),
+                next,
+            })
+        })
     }
 
     fn delete_secret(&self, key: &str) -> Result<()> {
         let url = format!(
-            "https://api.github.com/repos/{}/actions/secrets/{}",
-            self.repo, key
+            "{}/repos/{}/actions/secrets/{}",
+            self.api_base, self.repo, key
         );
 
         let response = self
             .with_github_headers(self.client.delete(&url))
             .send()
+            .inspect(super::log_response)
             .context("Failed to delete secret from GitHub")?;
 
         Self::check_response(response)?;
         Ok(())
     }
+
+    fn validate_secret_name(&self, name: &str) -> Result<()> {
+        validate_github_secret_name(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_server::{serve, Canned};
+    use super::*;
+
+    #[test]
+    fn test_list_secrets_follows_link_header() {
+        let (base, server) = serve(|base| {
+            vec![
+                Canned {
+                    headers: vec![format!(
+                        "Link: <{}/repositories/1/actions/secrets?per_page=100&page=2>; rel=\"next\"",
+                        base
+                    )],
+                    body: r#"{"total_count":3,"secrets":[{"name":"A"},{"name":"B"}]}"#.into(),
+                },
+                Canned {
+                    headers: vec![format!(
+                        "Link: <{}/repositories/1/actions/secrets?per_page=100&page=1>; rel=\"prev\"",
+                        base
+                    )],
+                    body: r#"{"total_count":3,"secrets":[{"name":"C"}]}"#.into(),
+                },
+            ]
+        });
+
+        let provider =
+            GitHubProvider::new("gh".into(), "me/app".into(), "token".into()).with_api_base(&base);
+        assert_eq!(provider.list_secrets().unwrap(), vec!["A", "B", "C"]);
+        assert_eq!(
+            server.join().unwrap(),
+            vec![
+                "/repos/me/app/actions/secrets?per_page=100",
+                "/repositories/1/actions/secrets?per_page=100&page=2",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_page_url() {
+        let link = "<https://api.github.com/repositories/1/actions/secrets?per_page=100&page=2>; rel=\"next\", \
+                    <https://api.github.com/repositories/1/actions/secrets?per_page=100&page=3>; rel=\"last\"";
+        assert_eq!(
+            next_page_url(link).as_deref(),
+            Some("https://api.github.com/repositories/1/actions/secrets?per_page=100&page=2")
+        );
+
+        let last = "<https://api.github.com/repositories/1/actions/secrets?page=1>; rel=\"prev\", \
+                    <https://api.github.com/repositories/1/actions/secrets?page=1>; rel=\"first\"";
+        assert_eq!(next_page_url(last), None);
+    }
+
+    #[test]
+    fn test_valid_github_secret_names() {
+        assert!(validate_github_secret_name("API_KEY").is_ok());
+        assert!(validate_github_secret_name("_PRIVATE").is_ok());
+        assert!(validate_github_secret_name("TOKEN_2").is_ok());
+    }
+
+    #[test]
+    fn test_invalid_github_secret_names() {
+        assert!(validate_github_secret_name("").is_err());
+        assert!(validate_github_secret_name("2FA_CODE").is_err());
+        assert!(validate_github_secret_name("api_key").is_err());
+        assert!(validate_github_secret_name("API-KEY").is_err());
+        assert!(validate_github_secret_name("GITHUB_TOKEN").is_err());
+    }
 }
@@ -1,2 +1,147 @@
 pub mod cloudflare;
 pub mod github;
+pub mod vercel;
+
+use anyhow::{bail, Result};
+use reqwest::blocking::Response;
+use std::collections::HashSet;
+
+/// Debug-log a provider API response by status and URL (never headers or body)
+fn log_response(response: &Response) {
+    log::debug!("HTTP {} from {}", response.status(), response.url());
+}
+
+/// One page of a secret listing and the token for the next page, if any.
+struct Page {
+    names: Vec<String>,
+    next: Option<String>,
+}
+
+/// Fetch pages until one comes back without a next token, accumulating the
+/// names. `fetch` gets `None` for the first page. A token seen before means the
+/// API is cycling, which is an error rather than an endless loop.
+fn collect_pages<F>(mut fetch: F) -> Result<Vec<String>>
+where
+    F: FnMut(Option<&str>) -> Result<Page>,
+{
+    let mut names = Vec::new();
+    let mut seen = HashSet::new();
+    let mut token: Option<String> = None;
+    loop {
+        let page = fetch(token.as_deref())?;
+        names.extend(page.names);
+        match page.next {
+            None => return Ok(names),
+            Some(next) if !seen.insert(next.clone()) => {
+                bail!("Pagination returned page token '{}' twice", next)
+            }
+            Some(next) => token = Some(next),
+        }
+    }
+}
+
+/// Minimal HTTP server answering each request with the next canned response,
+/// for exercising providers against multi-page listings.
+#[cfg(test)]
+mod test_server {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// A canned response: extra header lines (without CRLF) and a JSON body
+    pub struct Canned {
+        pub headers: Vec<String>,
+        pub body: String,
+    }
+
+    /// Serve the responses built by `responses` (given the server's base URL),
+    /// one per connection. The handle yields each request's path and query.
+    pub fn serve<F>(responses: F) -> (String, JoinHandle<Vec<String>>)
+    where
+        F: FnOnce(&str) -> Vec<Canned>,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let responses = responses(&base);
+
+        let handle = thread::spawn(move || {
+            let mut paths = Vec::new();
+            for canned in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                paths.push(request_line.split_whitespace().nth(1).unwrap().to_string());
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+
+                let mut head = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+                    canned.body.len()
+                );
+                for header in &canned.headers {
+                    head.push_str(header);
+                    head.push_str("\r\n");
+                }
+                write!(stream, "{}\r\n{}", head, canned.body).unwrap();
+            }
+            paths
+        });
+
+        (base, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_pages_follows_tokens() {
+        let mut requested = Vec::new();
+        let names = collect_pages(|token| {
+            requested.push(token.map(str::to_string));
+            let page = match token {
+                None => Page {
+                    names: vec!["A".into(), "B".into()],
+                    next: Some("2".into()),
+                },
+                Some("2") => Page {
+                    names: vec!["C".into()],
+                    next: Some("3".into()),
+                },
+                Some(_) => Page {
+                    names: vec!["D".into()],
+                    next: None,
+                },
+            };
+            Ok(page)
+        })
+        .unwrap();
+
+        assert_eq!(names, vec!["A", "B", "C", "D"]);
+        assert_eq!(requested, vec![None, Some("2".into()), Some("3".into())]);
+    }
+
+    #[test]
+    fn test_collect_pages_rejects_cycles() {
+        let mut calls = 0;
+        let result = collect_pages(|token| {
+            calls += 1;
+            let (name, next) = match token {
+                None => ("A", "b"),
+                Some("b") => ("B", "c"),
+                _ => ("C", "b"),
+            };
+            Ok(Page {
+                names: vec![name.into()],
+                next: Some(next.into()),
+            })
+        });
+
+        assert!(result.is_err());
+        assert_eq!(calls, 3);
+    }
+}
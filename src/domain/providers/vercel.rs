@@ -0,0 +1,230 @@
+use crate::domain::provider::Provider;
+use anyhow::{anyhow, Context, Result};
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+/// Deployment environments a Vercel env var can target
+pub const VERCEL_TARGETS: &[&str] = &["production", "preview", "development"];
+
+/// Vercel Project Environment Variables Provider
+pub struct VercelProvider {
+    provider_id: String,
+    project_id: String,
+    token: String,
+    target: String,
+    client: Client,
+}
+
+#[derive(Serialize)]
+struct EnvPayload<'a> {
+    key: &'a str,
+    value: &'a str,
+    #[serde(rename = "type")]
+    env_type: &'a str,
+    target: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+struct EnvUpdatePayload<'a> {
+    value: &'a str,
+    #[serde(rename = "type")]
+    env_type: &'a str,
+    target: Vec<&'a str>,
+}
+
+#[derive(Deserialize)]
+struct EnvList {
+    envs: Vec<EnvInfo>,
+}
+
+#[derive(Deserialize)]
+struct EnvInfo {
+    id: String,
+    key: String,
+    #[serde(default)]
+    target: Vec<String>,
+}
+
+impl VercelProvider {
+    pub fn new(provider_id: String, project_id: String, token: String, target: String) -> Self {
+        Self {
+            provider_id,
+            project_id,
+            token,
+            target,
+            client: Client::new(),
+        }
+    }
+
+    fn with_vercel_headers(&self, builder: RequestBuilder) -> RequestBuilder {
+        builder
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("Content-Type", "application/json")
+    }
+
+    fn env_url(&self, version: &str) -> String {
+        format!(
+            "https://api.vercel.com/{}/projects/{}/env",
+            version, self.project_id
+        )
+    }
+
+    fn check_response(response: Response) -> Result<Response> {
+        if response.status().is_success() {
+            return Ok(response);
+        }
+        Err(anyhow!(
+            "Vercel API error: {} - {}",
+            response.status(),
+            response.text().unwrap_or_default()
+        ))
+    }
+
+    fn list_envs(&self) -> Result<Vec<EnvInfo>> {
+        let response = self
+            .with_vercel_headers(self.client.get(self.env_url("v10")))
+            .send()
+            .inspect(super::log_response)
+            .context("Failed to list environment variables from Vercel")?;
+
+        Ok(Self::check_response(response)?
+            .json::<EnvList>()
+            .context("Failed to parse Vercel env list response")?
+            .envs)
+    }
+
+    /// Find the id of `key` in this provider's target environment
+    fn find_env_id(&self, key: &str) -> Result<Option<String>> {
+        Ok(self
+            .list_envs()?
+            .into_iter()
+            .find(|env| env.key == key && env.target.contains(&self.target))
+            .map(|env| env.id))
+    }
+}
+
+/// Vercel env var keys must match `[A-Za-z_][A-Za-z0-9_]*`.
+fn validate_vercel_env_name(name: &str) -> Result<()> {
+    let mut chars = name.chars();
+    let valid_start = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+    let valid_rest = chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if !valid_start || !valid_rest {
+        return Err(anyhow!(
+            "Invalid Vercel environment variable name '{}': must match [A-Za-z_][A-Za-z0-9_]*",
+            name
+        ));
+    }
+
+    Ok(())
+}
+
+impl Provider for VercelProvider {
+    fn provider_type(&self) -> &str {
+        "vercel"
+    }
+
+    fn provider_id(&self) -> &str {
+        &self.provider_id
+    }
+
+    fn push_secret(&self, key: &str, value: &str) -> Result<()> {
+        let payload = EnvPayload {
+            key,
+            value,
+            env_type: "encrypted",
+            target: vec![self.target.as_str()],
+        };
+
+        let response = self
+            .with_vercel_headers(self.client.post(self.env_url("v10")))
+            .json(&payload)
+            .send()
+            .inspect(super::log_response)
+            .context("Failed to push environment variable to Vercel")?;
+
+        if response.status().is_success() {
+            return Ok(());
+        }
+
+        // Vercel rejects creating a key that already exists; update it instead.
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        let already_exists = status == StatusCode::CONFLICT || body.contains("ENV_ALREADY_EXISTS");
+        if !already_exists {
+            return Err(anyhow!("Vercel API error: {} - {}", status, body));
+        }
+
+        let id = self.find_env_id(key)?.ok_or_else(|| {
+            anyhow!(
+                "Vercel reported '{}' as existing but it was not found for target '{}'",
+                key,
+                self.target
+            )
+        })?;
+
+        let update = EnvUpdatePayload {
+            value,
+            env_type: "encrypted",
+            target: vec![self.target.as_str()],
+        };
+        let response = self
+            .with_vercel_headers(self.client.patch(format!("{}/{}", self.env_url("v9"), id)))
+            .json(&update)
+            .send()
+            .inspect(super::log_response)
+            .context("Failed to update environment variable on Vercel")?;
+
+        Self::check_response(response)?;
+        Ok(())
+    }
+
+    fn list_secrets(&self) -> Result<Vec<String>> {
+        Ok(self
+            .list_envs()?
+            .into_iter()
+            .filter(|env| env.target.contains(&self.target))
+            .map(|env| env.key)
+            .collect())
+    }
+
+    fn delete_secret(&self, key: &str) -> Result<()> {
+        let id = self.find_env_id(key)?.ok_or_else(|| {
+            anyhow!(
+                "Environment variable '{}' not found for target '{}'",
+                key,
+                self.target
+            )
+        })?;
+
+        let response = self
+            .with_vercel_headers(self.client.delete(format!("{}/{}", self.env_url("v9"), id)))
+            .send()
+            .inspect(super::log_response)
+            .context("Failed to delete environment variable from Vercel")?;
+
+        Self::check_response(response)?;
+        Ok(())
+    }
+
+    fn validate_secret_name(&self, name: &str) -> Result<()> {
+        validate_vercel_env_name(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vercel_env_names() {
+        assert!(validate_vercel_env_name("DATABASE_URL").is_ok());
+        assert!(validate_vercel_env_name("next_public_key").is_ok());
+        assert!(validate_vercel_env_name("").is_err());
+        assert!(validate_vercel_env_name("1TOKEN").is_err());
+        assert!(validate_vercel_env_name("API-KEY").is_err());
+    }
+}
@@ -0,0 +1,69 @@
+//! One-time recovery codes stored on a hand.
+//!
+//! Codes live newline-separated in the `recovery_codes` card. Consumed codes
+//! are tracked by index in a parallel `recovery_codes_used` card so a code is
+//! never handed out twice.
+
+use std::collections::BTreeSet;
+
+pub const RECOVERY_CODES_CARD: &str = "recovery_codes";
+pub const RECOVERY_CODES_USED_CARD: &str = "recovery_codes_used";
+
+/// Split the `recovery_codes` card into individual codes, skipping blank lines.
+pub fn parse_codes(codes: &str) -> Vec<&str> {
+    codes
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Parse the comma-separated indices of consumed codes. Invalid entries are ignored.
+pub fn parse_used(used: &str) -> BTreeSet<usize> {
+    used.split(',')
+        .filter_map(|index| index.trim().parse().ok())
+        .collect()
+}
+
+pub fn format_used(used: &BTreeSet<usize>) -> String {
+    used.iter()
+        .map(|index| index.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Return the index and value of the first code that has not been consumed.
+pub fn next_unused<'a>(codes: &[&'a str], used: &BTreeSet<usize>) -> Option<(usize, &'a str)> {
+    codes
+        .iter()
+        .enumerate()
+        .find(|(index, _)| !used.contains(index))
+        .map(|(index, code)| (index, *code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_unused_skips_consumed() {
+        let codes = parse_codes("aaaa-1111\n\nbbbb-2222\ncccc-3333\n");
+        assert_eq!(codes.len(), 3);
+
+        let mut used = parse_used("");
+        assert_eq!(next_unused(&codes, &used), Some((0, "aaaa-1111")));
+
+        used.insert(0);
+        assert_eq!(next_unused(&codes, &used), Some((1, "bbbb-2222")));
+
+        used.insert(1);
+        used.insert(2);
+        assert_eq!(next_unused(&codes, &used), None);
+    }
+
+    #[test]
+    fn test_used_roundtrip() {
+        let used = parse_used("2, 0,bogus,1");
+        assert_eq!(format_used(&used), "0,1,2");
+    }
+}
@@ -3,8 +3,10 @@ use regex::Regex;
 use std::path::Path;
 use std::sync::LazyLock;
 
+use crate::domain::error::{HcError, ResolveError};
+use crate::domain::hand::Hand;
 use crate::domain::uri::SecretUri;
-use crate::infrastructure::KeyringManager;
+use crate::infrastructure::{DeckRegistry, KeyringManager};
 use crate::multi_deck_context::MultiDeckContext;
 
 static TEMPLATE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
@@ -20,22 +22,77 @@ impl SecretResolver {
         keyring: &KeyringManager,
         config_dir: &Path,
     ) -> Result<String> {
+        let (value, _) =
+            Self::resolve_uri(uri_str, default_deck, keyring, config_dir).map_err(HcError::from)?;
+        Ok(value)
+    }
+
+    /// Resolve `uri_str` to its value and the URI that actually matched, which
+    /// differs from the parsed one when the deck/hand shorthand was used.
+    fn resolve_uri(
+        uri_str: &str,
+        default_deck: Option<&str>,
+        keyring: &KeyringManager,
+        config_dir: &Path,
+    ) -> Result<(String, SecretUri), ResolveError> {
         let expanded = SecretUri::expand_env_vars(uri_str);
-        let uri = SecretUri::parse(&expanded)?;
+        let uri = SecretUri::parse(&expanded)
+            .map_err(|e| ResolveError::InvalidUri(format!("{:#}", e)))?;
+
+        match Self::lookup(&uri, default_deck, keyring, config_dir) {
+            Ok(value) => Ok((value, uri)),
+            // `hc://deck/hand` reads as hand/card first; fall back to the
+            // deck/hand shorthand only when such a deck is registered.
+            Err(ResolveError::HandNotFound(hand)) => match uri.deck_shorthand() {
+                Some(shorthand) if Self::deck_registered(&shorthand, config_dir) => {
+                    let value = Self::lookup(&shorthand, default_deck, keyring, config_dir)?;
+                    Ok((value, shorthand))
+                }
+                _ => Err(ResolveError::HandNotFound(hand)),
+            },
+            Err(e) => Err(e),
+        }
+    }
 
+    fn lookup(
+        uri: &SecretUri,
+        default_deck: Option<&str>,
+        keyring: &KeyringManager,
+        config_dir: &Path,
+    ) -> Result<String, ResolveError> {
         let deck_name = uri.deck.as_deref().or(default_deck);
-        let ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+        let ctx = MultiDeckContext::load(deck_name, keyring, config_dir)
+            .map_err(|e| ResolveError::DeckUnavailable(format!("{:#}", e)))?;
 
         let hand = ctx
             .inner
             .deck
             .get_hand(&uri.hand)
-            .map_err(|e| anyhow::anyhow!("{}", e))?;
+            .map_err(|_| ResolveError::HandNotFound(uri.hand.clone()))?;
+
+        Self::card_value(hand, uri)
+    }
+
+    fn deck_registered(uri: &SecretUri, config_dir: &Path) -> bool {
+        uri.deck.as_deref().is_some_and(|name| {
+            DeckRegistry::load(config_dir).is_ok_and(|registry| registry.get_deck(name).is_ok())
+        })
+    }
 
-        hand.cards
-            .get(&uri.card)
-            .cloned()
-            .ok_or_else(|| anyhow::anyhow!("Card '{}' not found in hand '{}'", uri.card, uri.hand))
+    /// Value of the card `uri` names, or the hand's default card when it
+    /// names none.
+    fn card_value(hand: &Hand, uri: &SecretUri) -> Result<String, ResolveError> {
+        let found = match uri.card_key() {
+            Some(key) => hand.cards.get(&key).ok_or(key),
+            None => hand
+                .default_card()
+                .map(|(_, value)| value)
+                .ok_or_else(|| "(default)".to_string()),
+        };
+        found.cloned().map_err(|card| ResolveError::CardNotFound {
+            hand: uri.hand.clone(),
+            card,
+        })
     }
 
     pub fn resolve_template(
@@ -44,19 +101,33 @@ impl SecretResolver {
         keyring: &KeyringManager,
         config_dir: &Path,
     ) -> Result<String> {
+        Self::resolve_template_with_hands(template, default_deck, keyring, config_dir)
+            .map(|(rendered, _)| rendered)
+    }
+
+    /// Like [`Self::resolve_template`], also returning the distinct hands the
+    /// URIs resolved to, in order of first appearance.
+    pub fn resolve_template_with_hands(
+        template: &str,
+        default_deck: Option<&str>,
+        keyring: &KeyringManager,
+        config_dir: &Path,
+    ) -> Result<(String, Vec<String>)> {
         let mut replacements = Vec::new();
+        let mut resolved = Vec::new();
         let mut errors = Vec::new();
 
         for cap in TEMPLATE_REGEX.captures_iter(template) {
             let full_match = cap.get(0).unwrap();
             let uri_str = full_match.as_str().trim();
 
-            match Self::resolve(uri_str, default_deck, keyring, config_dir) {
-                Ok(value) => {
+            match Self::resolve_uri(uri_str, default_deck, keyring, config_dir) {
+                Ok((value, uri)) => {
                     replacements.push((full_match.range(), value));
+                    resolved.push(uri);
                 }
                 Err(e) => {
-                    errors.push(format!("{}: {}", uri_str, e));
+                    errors.push(format!("{}: {}", uri_str, HcError::from(e)));
                 }
             }
         }
@@ -70,7 +141,18 @@ impl SecretResolver {
             result.replace_range(range.clone(), value);
         }
 
-        Ok(result)
+        Ok((result, Self::distinct_hands(&resolved)))
+    }
+
+    /// Distinct hand names of `uris`, in order of first appearance
+    fn distinct_hands(uris: &[SecretUri]) -> Vec<String> {
+        let mut hands: Vec<String> = Vec::new();
+        for uri in uris {
+            if !hands.contains(&uri.hand) {
+                hands.push(uri.hand.clone());
+            }
+        }
+        hands
     }
 
     pub fn has_uri_references(text: &str) -> bool {
@@ -92,4 +174,66 @@ mod tests {
         assert!(!SecretResolver::has_uri_references("plain text"));
         assert!(!SecretResolver::has_uri_references("http://example.com"));
     }
+
+    #[test]
+    fn test_distinct_hands_follow_resolved_uris() {
+        let parse = |uri: &str| SecretUri::parse(uri).unwrap();
+        let uris = vec![
+            parse("hc://prod/db/user"),
+            parse("hc://prod/db/password"),
+            parse("hc://api/token"),
+        ];
+        assert_eq!(SecretResolver::distinct_hands(&uris), vec!["db", "api"]);
+
+        // `hc://prod/db` resolved through the deck/hand shorthand names hand `db`
+        let shorthand = parse("hc://prod/db").deck_shorthand().unwrap();
+        assert_eq!(SecretResolver::distinct_hands(&[shorthand]), vec!["db"]);
+    }
+
+    fn hand(cards: &[(&str, &str)]) -> Hand {
+        let cards = cards
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        Hand::new("db".to_string(), cards, None)
+    }
+
+    #[test]
+    fn test_card_value_shorthand_uses_default_card() {
+        let shorthand = SecretUri::parse("hc://prod/db")
+            .unwrap()
+            .deck_shorthand()
+            .unwrap();
+
+        let with_password = hand(&[("username", "admin"), ("password", "s3cret")]);
+        assert_eq!(
+            SecretResolver::card_value(&with_password, &shorthand).unwrap(),
+            "s3cret"
+        );
+
+        let single = hand(&[("token", "abc")]);
+        assert_eq!(
+            SecretResolver::card_value(&single, &shorthand).unwrap(),
+            "abc"
+        );
+
+        let empty = hand(&[]);
+        assert!(matches!(
+            SecretResolver::card_value(&empty, &shorthand),
+            Err(ResolveError::CardNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_card_value_explicit_card() {
+        let uri = SecretUri::parse("hc://prod/db/username").unwrap();
+        let h = hand(&[("username", "admin"), ("password", "s3cret")]);
+        assert_eq!(SecretResolver::card_value(&h, &uri).unwrap(), "admin");
+
+        let uri = SecretUri::parse("hc://prod/db/port").unwrap();
+        assert!(matches!(
+            SecretResolver::card_value(&h, &uri),
+            Err(ResolveError::CardNotFound { card, .. }) if card == "port"
+        ));
+    }
 }
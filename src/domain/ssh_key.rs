@@ -1,6 +1,7 @@
 use crate::domain::Deck;
 
 use anyhow::{bail, Result};
+use std::collections::HashMap;
 
 const KEY_FORMATS: &[(&str, SshKeyType)] = &[
     ("OPENSSH PRIVATE KEY", SshKeyType::OpenSsh),
@@ -23,6 +24,51 @@ pub fn validate_private_key(key: &str) -> Result<SshKeyType> {
     bail!("Invalid SSH private key format. Supported formats: OpenSSH, RSA (PEM), ECDSA, Ed25519");
 }
 
+/// How an SSH hand authenticates. Each method owns some cards, and a hand
+/// should only carry one method's cards: `ssh connect` prefers `alias`, then
+/// `password`, so a leftover card would shadow a newly added method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SshAuthMethod {
+    Alias,
+    Password,
+    Key,
+}
+
+impl SshAuthMethod {
+    /// Cards to drop when switching to this method; the new method's own cards
+    /// are written afterwards, so a new key also clears the old key's extras.
+    fn stale_cards(self) -> &'static [&'static str] {
+        match self {
+            Self::Alias => &[
+                "username",
+                "hostname",
+                "host",
+                "password",
+                "private_key",
+                "public_key",
+                "passphrase",
+            ],
+            Self::Password => &["alias", "private_key", "public_key", "passphrase"],
+            Self::Key => &["alias", "password", "public_key", "passphrase"],
+        }
+    }
+}
+
+/// Merge `updates` into an existing SSH hand's `cards`. When `method` is set,
+/// cards belonging to other auth methods are removed first.
+pub fn update_ssh_cards(
+    cards: &mut HashMap<String, String>,
+    updates: HashMap<String, String>,
+    method: Option<SshAuthMethod>,
+) {
+    if let Some(method) = method {
+        for key in method.stale_cards() {
+            cards.remove(*key);
+        }
+    }
+    cards.extend(updates);
+}
+
 pub fn find_hand_by_name_or_alias(deck: &Deck, target: &str) -> Option<String> {
     if deck.get_hand(target).is_ok() {
         return Some(target.to_string());
@@ -70,4 +116,58 @@ mod tests {
         let key = "invalid key content";
         assert!(validate_private_key(key).is_err());
     }
+
+    fn cards(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_update_ssh_cards_switches_password_to_key() {
+        let mut existing = cards(&[
+            ("username", "deploy"),
+            ("hostname", "example.com"),
+            ("password", "old"),
+            ("proxy_jump", "bastion"),
+        ]);
+        update_ssh_cards(
+            &mut existing,
+            cards(&[("private_key", "KEY")]),
+            Some(SshAuthMethod::Key),
+        );
+
+        assert!(!existing.contains_key("password"));
+        assert_eq!(existing["private_key"], "KEY");
+        assert_eq!(existing["username"], "deploy");
+        assert_eq!(existing["proxy_jump"], "bastion");
+    }
+
+    #[test]
+    fn test_update_ssh_cards_switches_key_to_password() {
+        let mut existing = cards(&[
+            ("private_key", "KEY"),
+            ("public_key", "PUB"),
+            ("passphrase", "secret"),
+        ]);
+        update_ssh_cards(
+            &mut existing,
+            cards(&[("password", "new")]),
+            Some(SshAuthMethod::Password),
+        );
+
+        assert_eq!(existing, cards(&[("password", "new")]));
+    }
+
+    #[test]
+    fn test_update_ssh_cards_without_method_keeps_auth() {
+        let mut existing = cards(&[("private_key", "KEY"), ("passphrase", "old")]);
+        update_ssh_cards(&mut existing, cards(&[("passphrase", "new")]), None);
+
+        assert_eq!(
+            existing,
+            cards(&[("private_key", "KEY"), ("passphrase", "new")])
+        );
+    }
 }
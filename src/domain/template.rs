@@ -59,13 +59,15 @@ impl TemplateEngine {
         Ok(result)
     }
 
-    #[allow(dead_code)]
-    fn expand_hand(hand: &Hand) -> String {
-        hand.cards
+    /// All cards as `KEY=value` lines, sorted by key
+    pub fn expand_hand(hand: &Hand) -> String {
+        let mut lines: Vec<String> = hand
+            .cards
             .iter()
             .map(|(k, v)| format!("{}={}", k.to_uppercase(), v))
-            .collect::<Vec<_>>()
-            .join("\n")
+            .collect();
+        lines.sort();
+        lines.join("\n")
     }
 
     /// Resolve a template string that may contain {{hand_name.card}} references
@@ -1,36 +1,161 @@
 use anyhow::{Context, Result};
 use chrono::Utc;
+use std::fmt;
+use std::str::FromStr;
 
 const TOTP_PERIOD: u64 = 30;
 const TOTP_DIGITS: u32 = 6;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TotpAlgorithm {
+    #[default]
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+impl FromStr for TotpAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace('-', "").as_str() {
+            "sha1" => Ok(Self::Sha1),
+            "sha256" => Ok(Self::Sha256),
+            "sha512" => Ok(Self::Sha512),
+            _ => Err(format!(
+                "Unknown TOTP algorithm '{}' (expected sha1, sha256 or sha512)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for TotpAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Sha1 => "SHA1",
+            Self::Sha256 => "SHA256",
+            Self::Sha512 => "SHA512",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Code generation parameters; the defaults match RFC 6238 (SHA1, 6 digits, 30s)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TotpParams {
+    pub algorithm: TotpAlgorithm,
+    pub digits: u32,
+    pub period: u64,
+}
+
+impl Default for TotpParams {
+    fn default() -> Self {
+        Self {
+            algorithm: TotpAlgorithm::Sha1,
+            digits: TOTP_DIGITS,
+            period: TOTP_PERIOD,
+        }
+    }
+}
+
+impl TotpParams {
+    pub fn validate(&self) -> Result<()> {
+        if !(6..=8).contains(&self.digits) {
+            anyhow::bail!("TOTP digits must be between 6 and 8, got {}", self.digits);
+        }
+        if self.period == 0 || self.period > 300 {
+            anyhow::bail!("TOTP period must be 1-300 seconds, got {}", self.period);
+        }
+        Ok(())
+    }
+}
+
 pub struct TotpService;
 
 impl TotpService {
     /// Generate a TOTP code from a base32-encoded secret
-    pub fn generate_code(secret: &str) -> Result<String> {
+    pub fn generate_code(secret: &str, params: &TotpParams) -> Result<String> {
+        Self::generate_code_at(secret, params, Utc::now().timestamp() as u64)
+    }
+
+    /// Generate the code for the window containing `timestamp` (Unix seconds)
+    pub fn generate_code_at(secret: &str, params: &TotpParams, timestamp: u64) -> Result<String> {
         let normalized = Self::normalize_secret(secret);
         Self::validate_secret(&normalized)?;
+        params.validate()?;
 
         let decoded = base32::decode(base32::Alphabet::RFC4648 { padding: false }, &normalized)
             .context("Failed to decode base32 secret")?;
 
+        let totp = match params.algorithm {
+            TotpAlgorithm::Sha1 => totp_lite::totp_custom::<totp_lite::Sha1>(
+                params.period,
+                params.digits,
+                &decoded,
+                timestamp,
+            ),
+            TotpAlgorithm::Sha256 => totp_lite::totp_custom::<totp_lite::Sha256>(
+                params.period,
+                params.digits,
+                &decoded,
+                timestamp,
+            ),
+            TotpAlgorithm::Sha512 => totp_lite::totp_custom::<totp_lite::Sha512>(
+                params.period,
+                params.digits,
+                &decoded,
+                timestamp,
+            ),
+        };
+
+        Ok(format!("{:0width$}", totp, width = params.digits as usize))
+    }
+
+    /// Get remaining seconds in the current TOTP window
+    pub fn get_remaining_seconds(period: u64) -> u64 {
         let current_time = Utc::now().timestamp() as u64;
+        period - (current_time % period)
+    }
 
-        let totp = totp_lite::totp_custom::<totp_lite::Sha1>(
-            TOTP_PERIOD,
-            TOTP_DIGITS,
-            &decoded,
-            current_time,
-        );
+    /// Serialize a secret for storage in the `totp` hand. Default parameters
+    /// keep the bare secret; anything else is stored as an otpauth URI.
+    pub fn to_stored_value(label: &str, secret: &str, params: &TotpParams) -> String {
+        if *params == TotpParams::default() {
+            return secret.to_string();
+        }
+        format!(
+            "otpauth://totp/{}?secret={}&algorithm={}&digits={}&period={}",
+            Self::encode_label(label),
+            Self::normalize_secret(secret),
+            params.algorithm,
+            params.digits,
+            params.period
+        )
+    }
 
-        Ok(format!("{:0width$}", totp, width = TOTP_DIGITS as usize))
+    /// Percent-encode an otpauth label, keeping unreserved characters and the
+    /// `issuer:account` separator so `?`, `&`, `#`, `/` and `%` cannot leak
+    /// into the URI's structure.
+    fn encode_label(label: &str) -> String {
+        label
+            .bytes()
+            .map(|b| match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b':' => {
+                    (b as char).to_string()
+                }
+                _ => format!("%{:02X}", b),
+            })
+            .collect()
     }
 
-    /// Get remaining seconds in current TOTP window
-    pub fn get_remaining_seconds() -> u64 {
-        let current_time = Utc::now().timestamp() as u64;
-        TOTP_PERIOD - (current_time % TOTP_PERIOD)
+    /// Parse a stored value back into its secret and parameters
+    pub fn from_stored_value(value: &str) -> Result<(String, TotpParams)> {
+        if value.starts_with("otpauth://") {
+            Self::parse_otpauth_uri(value)
+        } else {
+            Ok((value.to_string(), TotpParams::default()))
+        }
     }
 
     /// Normalize TOTP secret (remove spaces, convert to uppercase)
@@ -42,6 +167,46 @@ impl TotpService {
             .to_uppercase()
     }
 
+    /// Extract the base32 secret and parameters from an `otpauth://totp/...` URI
+    pub fn parse_otpauth_uri(uri: &str) -> Result<(String, TotpParams)> {
+        let rest = uri.strip_prefix("otpauth://totp/").ok_or_else(|| {
+            anyhow::anyhow!("Not an otpauth TOTP URI: expected 'otpauth://totp/...'")
+        })?;
+
+        let query = rest
+            .split_once('?')
+            .map(|(_, query)| query)
+            .ok_or_else(|| anyhow::anyhow!("otpauth URI has no parameters"))?;
+
+        let mut secret = None;
+        let mut params = TotpParams::default();
+        for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            match key.to_ascii_lowercase().as_str() {
+                "secret" => secret = Some(value.replace("%20", "")),
+                "algorithm" => params.algorithm = value.parse().map_err(anyhow::Error::msg)?,
+                "digits" => {
+                    params.digits = value
+                        .parse()
+                        .with_context(|| format!("Invalid digits in otpauth URI: '{}'", value))?
+                }
+                "period" => {
+                    params.period = value
+                        .parse()
+                        .with_context(|| format!("Invalid period in otpauth URI: '{}'", value))?
+                }
+                _ => {}
+            }
+        }
+
+        let secret = secret
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| anyhow::anyhow!("otpauth URI is missing the 'secret' parameter"))?;
+
+        Self::validate_secret(&secret)?;
+        params.validate()?;
+        Ok((secret, params))
+    }
+
     /// Validate a TOTP secret
     pub fn validate_secret(secret: &str) -> Result<()> {
         let normalized = Self::normalize_secret(secret);
@@ -61,6 +226,32 @@ impl TotpService {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_stored_value_round_trips_awkward_labels() {
+        let params = TotpParams {
+            algorithm: TotpAlgorithm::Sha256,
+            digits: 8,
+            period: 60,
+        };
+        let label = "Acme:me@example.com?secret=AAAA&x=1#frag/path 100%";
+        let stored = TotpService::to_stored_value(label, "JBSWY3DPEHPK3PXP", &params);
+
+        let path = stored
+            .strip_prefix("otpauth://totp/")
+            .unwrap()
+            .split('?')
+            .next()
+            .unwrap();
+        assert_eq!(
+            path,
+            "Acme:me%40example.com%3Fsecret%3DAAAA%26x%3D1%23frag%2Fpath%20100%25"
+        );
+
+        let (secret, parsed) = TotpService::from_stored_value(&stored).unwrap();
+        assert_eq!(secret, "JBSWY3DPEHPK3PXP");
+        assert_eq!(parsed, params);
+    }
+
     #[test]
     fn test_normalize_secret() {
         // Spaces and dashes
@@ -100,9 +291,60 @@ mod tests {
         assert!(TotpService::validate_secret("AB").is_err());
     }
 
+    #[test]
+    fn test_parse_otpauth_uri() {
+        let uri = "otpauth://totp/Example:alice@example.com?secret=JBSWY3DPEHPK3PXP&issuer=Example";
+        let (secret, params) = TotpService::parse_otpauth_uri(uri).unwrap();
+        assert_eq!(secret, "JBSWY3DPEHPK3PXP");
+        assert_eq!(params, TotpParams::default());
+
+        let uri = "otpauth://totp/x?secret=JBSWY3DPEHPK3PXP&algorithm=SHA256&digits=8&period=60";
+        let (_, params) = TotpService::parse_otpauth_uri(uri).unwrap();
+        assert_eq!(params.algorithm, TotpAlgorithm::Sha256);
+        assert_eq!(params.digits, 8);
+        assert_eq!(params.period, 60);
+
+        assert!(
+            TotpService::parse_otpauth_uri("otpauth://hotp/x?secret=JBSWY3DPEHPK3PXP").is_err()
+        );
+        assert!(TotpService::parse_otpauth_uri("otpauth://totp/x?issuer=Example").is_err());
+        assert!(TotpService::parse_otpauth_uri("https://example.com").is_err());
+    }
+
+    #[test]
+    fn test_stored_value_round_trip() {
+        let secret = "JBSWY3DPEHPK3PXP";
+        let default = TotpService::to_stored_value("github", secret, &TotpParams::default());
+        assert_eq!(default, secret);
+
+        let params = TotpParams {
+            algorithm: TotpAlgorithm::Sha512,
+            digits: 8,
+            period: 30,
+        };
+        let stored = TotpService::to_stored_value("my site", secret, &params);
+        assert_eq!(
+            TotpService::from_stored_value(&stored).unwrap(),
+            (secret.to_string(), params)
+        );
+
+        let code = TotpService::generate_code(secret, &params).unwrap();
+        assert_eq!(code.len(), 8);
+    }
+
+    #[test]
+    fn test_params_validation() {
+        let params = TotpParams {
+            digits: 10,
+            ..TotpParams::default()
+        };
+        assert!(params.validate().is_err());
+        assert!("md5".parse::<TotpAlgorithm>().is_err());
+    }
+
     #[test]
     fn test_remaining_seconds() {
-        let remaining = TotpService::get_remaining_seconds();
+        let remaining = TotpService::get_remaining_seconds(TOTP_PERIOD);
         assert!(remaining > 0 && remaining <= TOTP_PERIOD);
     }
 
@@ -111,10 +353,25 @@ mod tests {
         // Valid test secret from RFC 6238
         let secret = "JBSWY3DPEHPK3PXP";
 
-        let code = TotpService::generate_code(secret).unwrap();
+        let code = TotpService::generate_code(secret, &TotpParams::default()).unwrap();
 
         // Should be 6 digits
         assert_eq!(code.len(), 6);
         assert!(code.chars().all(|c| c.is_ascii_digit()));
     }
+
+    #[test]
+    fn test_generate_code_at_rfc6238_vector() {
+        // RFC 6238 appendix B: ASCII "12345678901234567890", T = 59
+        let secret = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+        let params = TotpParams {
+            digits: 8,
+            ..TotpParams::default()
+        };
+
+        assert_eq!(
+            TotpService::generate_code_at(secret, &params, 59).unwrap(),
+            "94287082"
+        );
+    }
 }
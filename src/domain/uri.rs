@@ -2,8 +2,12 @@ use anyhow::Result;
 use regex::Regex;
 use std::sync::LazyLock;
 
+// [deck/]hand/card or, following 1Password, deck/hand/section/card.
+// Two segments are read as hand/card; see `SecretUri::deck_shorthand` for
+// the deck/hand reading.
 static URI_REGEX: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"^(?:hc|op)://(?:([^/]+)/)?([^/]+)/(.+)$").expect("Failed to compile URI regex")
+    Regex::new(r"^(?:hc|op)://(?:([^/]+)/)?([^/]+)/(?:([^/]+)/)?([^/]+)$")
+        .expect("Failed to compile URI regex")
 });
 
 static ENV_VAR_REGEX: LazyLock<Regex> = LazyLock::new(|| {
@@ -14,7 +18,9 @@ static ENV_VAR_REGEX: LazyLock<Regex> = LazyLock::new(|| {
 pub struct SecretUri {
     pub deck: Option<String>,
     pub hand: String,
-    pub card: String,
+    pub section: Option<String>,
+    /// `None` means the hand's default card (see `Hand::default_card`).
+    pub card: Option<String>,
 }
 
 impl SecretUri {
@@ -34,7 +40,8 @@ impl SecretUri {
 
         let deck = caps.get(1).map(|m| m.as_str().to_string());
         let hand = caps.get(2).unwrap().as_str().to_string();
-        let card = caps.get(3).unwrap().as_str().to_string();
+        let section = caps.get(3).map(|m| m.as_str().to_string());
+        let card = caps.get(4).unwrap().as_str().to_string();
 
         if hand.is_empty() {
             anyhow::bail!("Hand name cannot be empty in URI: {}", uri);
@@ -43,7 +50,36 @@ impl SecretUri {
             anyhow::bail!("Card name cannot be empty in URI: {}", uri);
         }
 
-        Ok(Self { deck, hand, card })
+        Ok(Self {
+            deck,
+            hand,
+            section,
+            card: Some(card),
+        })
+    }
+
+    /// Re-read a two-segment URI as `deck/hand` with no card, so that
+    /// `hc://deck/hand` can point at the hand's default card.
+    pub fn deck_shorthand(&self) -> Option<Self> {
+        if self.deck.is_some() || self.section.is_some() {
+            return None;
+        }
+        Some(Self {
+            deck: Some(self.hand.clone()),
+            hand: self.card.clone()?,
+            section: None,
+            card: None,
+        })
+    }
+
+    /// Card key to look up: `section.card` for section-qualified URIs,
+    /// `None` when the URI names no card.
+    pub fn card_key(&self) -> Option<String> {
+        let card = self.card.as_ref()?;
+        Some(match &self.section {
+            Some(section) => format!("{}.{}", section, card),
+            None => card.clone(),
+        })
     }
 
     #[allow(dead_code)]
@@ -76,7 +112,7 @@ mod tests {
         let uri = SecretUri::parse("hc://production/database/password").unwrap();
         assert_eq!(uri.deck, Some("production".to_string()));
         assert_eq!(uri.hand, "database");
-        assert_eq!(uri.card, "password");
+        assert_eq!(uri.card.as_deref(), Some("password"));
     }
 
     #[test]
@@ -84,7 +120,7 @@ mod tests {
         let uri = SecretUri::parse("hc://github/token").unwrap();
         assert_eq!(uri.deck, None);
         assert_eq!(uri.hand, "github");
-        assert_eq!(uri.card, "token");
+        assert_eq!(uri.card.as_deref(), Some("token"));
     }
 
     #[test]
@@ -92,7 +128,23 @@ mod tests {
         let uri = SecretUri::parse("hc://aws/credentials/access_key").unwrap();
         assert_eq!(uri.deck, Some("aws".to_string()));
         assert_eq!(uri.hand, "credentials");
-        assert_eq!(uri.card, "access_key");
+        assert_eq!(uri.card.as_deref(), Some("access_key"));
+    }
+
+    #[test]
+    fn test_deck_shorthand() {
+        let uri = SecretUri::parse("hc://prod/db").unwrap();
+        let shorthand = uri.deck_shorthand().unwrap();
+        assert_eq!(shorthand.deck.as_deref(), Some("prod"));
+        assert_eq!(shorthand.hand, "db");
+        assert_eq!(shorthand.card, None);
+        assert_eq!(shorthand.card_key(), None);
+
+        // Only two-segment URIs are ambiguous
+        let uri = SecretUri::parse("hc://prod/db/password").unwrap();
+        assert!(uri.deck_shorthand().is_none());
+        let uri = SecretUri::parse("op://prod/db/creds/password").unwrap();
+        assert!(uri.deck_shorthand().is_none());
     }
 
     #[test]
@@ -147,7 +199,29 @@ mod tests {
         let uri = SecretUri::parse("op://production/database/password").unwrap();
         assert_eq!(uri.deck, Some("production".to_string()));
         assert_eq!(uri.hand, "database");
-        assert_eq!(uri.card, "password");
+        assert_eq!(uri.card.as_deref(), Some("password"));
+    }
+
+    #[test]
+    fn test_parse_op_section_qualified_uri() {
+        let uri = SecretUri::parse("op://production/database/credentials/password").unwrap();
+        assert_eq!(uri.deck, Some("production".to_string()));
+        assert_eq!(uri.hand, "database");
+        assert_eq!(uri.section, Some("credentials".to_string()));
+        assert_eq!(uri.card.as_deref(), Some("password"));
+        assert_eq!(uri.card_key().as_deref(), Some("credentials.password"));
+    }
+
+    #[test]
+    fn test_three_segments_are_deck_hand_card() {
+        let uri = SecretUri::parse("op://production/database/password").unwrap();
+        assert_eq!(uri.section, None);
+        assert_eq!(uri.card_key().as_deref(), Some("password"));
+    }
+
+    #[test]
+    fn test_parse_too_many_segments() {
+        assert!(SecretUri::parse("op://a/b/c/d/e").is_err());
     }
 
     #[test]
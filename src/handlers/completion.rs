@@ -43,9 +43,23 @@ pub fn handle_completion(shell: &str) -> Result<()> {
             println!("# Or save to ~/.config/fish/completions/hc.fish:");
             println!("#   hc completion fish > ~/.config/fish/completions/hc.fish");
         }
+        "powershell" => {
+            generate(shells::PowerShell, &mut cmd, "hc", &mut io::stdout());
+            println!();
+            println!("# To enable completion, add the following to your PowerShell profile:");
+            println!("#   hc completion powershell | Out-String | Invoke-Expression");
+            println!("# Or save to a file and dot-source it from $PROFILE:");
+            println!("#   hc completion powershell > hc.ps1");
+        }
+        "elvish" => {
+            generate(shells::Elvish, &mut cmd, "hc", &mut io::stdout());
+            println!();
+            println!("# To enable completion, add the following to ~/.config/elvish/rc.elv:");
+            println!("#   eval (hc completion elvish | slurp)");
+        }
         _ => {
             return Err(anyhow::anyhow!(
-                "Unsupported shell: {}. Supported shells: bash, zsh, fish",
+                "Unsupported shell: {}. Supported shells: bash, zsh, fish, powershell, elvish",
                 shell
             ));
         }
@@ -123,23 +137,22 @@ complete -c hc -n "__fish_seen_subcommand_from hand; and __fish_seen_subcommand_
     );
 }
 
+/// Prints known hand names for shell completion. Never fails: a missing deck,
+/// locked session or unreadable cache just yields no candidates.
 pub fn handle_complete_hands(deck_name: Option<&str>, config_dir: &Path) -> Result<()> {
-    let deck_name = match deck_name {
-        Some(name) => name.to_string(),
-        None => {
-            let registry = DeckRegistry::load(config_dir)?;
-            registry.get_active_deck()?.name
-        }
-    };
-
-    let config = Config::load(config_dir)?;
-    let session = SessionManager::new(config_dir, &deck_name, config.session_timeout_minutes);
-
-    let hand_names = session.load_card_names().unwrap_or_default();
-
-    for name in hand_names {
+    for name in completion_hand_names(deck_name, config_dir).unwrap_or_default() {
         println!("{}", name);
     }
 
     Ok(())
 }
+
+fn completion_hand_names(deck_name: Option<&str>, config_dir: &Path) -> Result<Vec<String>> {
+    let registry = DeckRegistry::load(config_dir)?;
+    let deck_name = registry.resolve_deck(deck_name)?.name;
+
+    let config = Config::load_for_deck(config_dir, &deck_name)?;
+    let session = SessionManager::new(config_dir, &deck_name, config.session_timeout_minutes);
+
+    session.load_card_names()
+}
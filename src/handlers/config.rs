@@ -1,40 +1,241 @@
 use anyhow::{Context, Result};
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
+use crate::cli::input;
+use crate::cli::output::status;
 use crate::cli::ConfigCommands;
-use crate::config::Config;
+use crate::config::{expand_tilde, Config};
+use crate::domain::HcError;
+use crate::infrastructure::{
+    get_biometric_auth, time_kdf, DeckRegistry, KDF_ITERATIONS, KDF_MEMORY_KIB,
+};
+
+pub fn handle_config(
+    subcommand: Option<ConfigCommands>,
+    assume_yes: bool,
+    deck_name: Option<&str>,
+    config_dir: &Path,
+) -> Result<()> {
+    if let Some(deck_name) = deck_name {
+        return handle_deck_config(subcommand, deck_name, config_dir);
+    }
 
-pub fn handle_config(subcommand: Option<ConfigCommands>, config_dir: &Path) -> Result<()> {
     let mut config = Config::load(config_dir)?;
 
     match subcommand {
-        None => {
+        None | Some(ConfigCommands::Show { json: false }) => {
             println!("\nCurrent Configuration:");
             println!("  Deck Path: {}", config.deck_path.display());
             println!(
                 "  Session Timeout: {} minutes",
                 config.session_timeout_minutes
             );
+            println!(
+                "  Biometric: {}",
+                if config.enable_biometric { "on" } else { "off" }
+            );
+            println!("  Non-secret Cards: {}", config.non_secret_cards.join(", "));
+        }
+        Some(ConfigCommands::Biometric { state }) => {
+            config.enable_biometric = state == "on";
+            config.save(config_dir)?;
+
+            if config.enable_biometric && !get_biometric_auth().is_available() {
+                eprintln!("⚠ Biometric authentication is not available on this system; the setting is saved but has no effect here.");
+            }
+            status!("✓ Biometric authentication turned {}", state);
+        }
+        Some(ConfigCommands::Show { json: true }) => {
+            let json =
+                serde_json::to_string_pretty(&config).context("Failed to serialize config")?;
+            println!("{}", json);
+        }
+        Some(ConfigCommands::Get { key }) => {
+            println!("{}", config.get(&key)?);
+        }
+        Some(ConfigCommands::Set { key, value }) => {
+            config.set(&key, &value)?;
+            config.save(config_dir)?;
+            status!("✓ {} updated to: {}", key, config.get(&key)?);
         }
         Some(ConfigCommands::DeckPath { path }) => {
-            let new_path = PathBuf::from(path);
-            let expanded_path = if new_path.starts_with("~") {
-                let home = dirs::home_dir().context("Failed to get home directory")?;
-                home.join(new_path.strip_prefix("~").unwrap())
-            } else {
-                new_path
-            };
+            let expanded_path = expand_tilde(&path)?;
 
             config.deck_path = expanded_path.clone();
             config.save(config_dir)?;
-            println!("✓ Deck path updated to: {}", expanded_path.display());
+            status!("✓ Deck path updated to: {}", expanded_path.display());
         }
         Some(ConfigCommands::SessionTimeout { minutes }) => {
             config.session_timeout_minutes = minutes;
             config.save(config_dir)?;
-            println!("✓ Session timeout updated to: {} minutes", minutes);
+            status!("✓ Session timeout updated to: {} minutes", minutes);
+        }
+        Some(ConfigCommands::Reset { all }) => handle_reset(&config, all, assume_yes, config_dir)?,
+        Some(ConfigCommands::Kdf {
+            benchmark,
+            target_ms,
+        }) => handle_kdf(benchmark, target_ms)?,
+    }
+
+    Ok(())
+}
+
+/// (memory KiB, iterations) pairs tried by `config kdf --benchmark`, weakest first
+const KDF_CANDIDATES: &[(u32, u32)] = &[
+    (KDF_MEMORY_KIB, KDF_ITERATIONS),
+    (32 * 1024, 2),
+    (64 * 1024, 2),
+    (64 * 1024, 3),
+    (128 * 1024, 3),
+    (256 * 1024, 3),
+    (256 * 1024, 4),
+];
+
+fn handle_kdf(benchmark: bool, target_ms: u64) -> Result<()> {
+    println!(
+        "Argon2id: {} MiB memory, {} iterations",
+        KDF_MEMORY_KIB / 1024,
+        KDF_ITERATIONS
+    );
+    if !benchmark {
+        return Ok(());
+    }
+
+    println!("\n  {:>8}  {:>10}  {:>9}", "Memory", "Iterations", "Time");
+    let mut recommended = None;
+    for &(memory_kib, iterations) in KDF_CANDIDATES {
+        let elapsed = time_kdf(memory_kib, iterations).map_err(HcError::from)?;
+        let current = (memory_kib, iterations) == (KDF_MEMORY_KIB, KDF_ITERATIONS);
+        println!(
+            "  {:>4} MiB  {:>10}  {:>6} ms{}",
+            memory_kib / 1024,
+            iterations,
+            elapsed.as_millis(),
+            if current { "  (current)" } else { "" }
+        );
+        if elapsed.as_millis() > u128::from(target_ms) {
+            // Candidates only get slower from here
+            break;
+        }
+        recommended = Some((memory_kib, iterations));
+    }
+
+    match recommended {
+        Some((memory_kib, iterations)) => println!(
+            "\nStrongest setting under {} ms: {} MiB, {} iterations",
+            target_ms,
+            memory_kib / 1024,
+            iterations
+        ),
+        None => println!(
+            "\n⚠ No setting finished under {} ms on this machine",
+            target_ms
+        ),
+    }
+    println!("Deck files use the built-in cost above; it cannot be changed per deck yet.");
+    Ok(())
+}
+
+/// Rewrite config.toml with defaults. Decks, sessions and the registry are
+/// left alone.
+fn handle_reset(config: &Config, all: bool, assume_yes: bool, config_dir: &Path) -> Result<()> {
+    let defaults = config.reset(config_dir, all);
+    let changed = config.changed_keys(&defaults);
+
+    if changed.is_empty() {
+        status!("✓ Configuration already matches the defaults");
+        return Ok(());
+    }
+
+    println!("\nSettings to reset:");
+    for key in &changed {
+        match (config.get(key), defaults.get(key)) {
+            (Ok(old), Ok(new)) => println!("  {}: {} → {}", key, old, new),
+            _ => println!("  {}", key),
         }
     }
 
+    if !assume_yes && !input::prompt_confirm(&format!("Reset {} setting(s)?", changed.len()))? {
+        println!("Cancelled.");
+        return Ok(());
+    }
+
+    defaults.save(config_dir)?;
+    status!("✓ Configuration reset to defaults");
     Ok(())
 }
+
+/// `hc config --deck <name> ...`: read and write the per-deck overrides
+/// stored in the deck registry. Only timeout and biometric can be overridden.
+fn handle_deck_config(
+    subcommand: Option<ConfigCommands>,
+    deck_name: &str,
+    config_dir: &Path,
+) -> Result<()> {
+    let registry = DeckRegistry::load(config_dir)?;
+    let deck = registry.get_deck(deck_name)?;
+    let mut overrides = deck.overrides.clone();
+
+    let mut effective = Config::load(config_dir)?;
+    effective.apply_overrides(&overrides);
+
+    match subcommand {
+        None | Some(ConfigCommands::Show { json: false }) => {
+            println!("\nConfiguration for deck '{}':", deck_name);
+            println!(
+                "  Session Timeout: {} minutes{}",
+                effective.session_timeout_minutes,
+                override_marker(overrides.session_timeout_minutes.is_some())
+            );
+            println!(
+                "  Biometric: {}{}",
+                if effective.enable_biometric {
+                    "on"
+                } else {
+                    "off"
+                },
+                override_marker(overrides.enable_biometric.is_some())
+            );
+            return Ok(());
+        }
+        Some(ConfigCommands::Show { json: true }) => {
+            let json =
+                serde_json::to_string_pretty(&effective).context("Failed to serialize config")?;
+            println!("{}", json);
+            return Ok(());
+        }
+        Some(ConfigCommands::Get { key }) => {
+            println!("{}", effective.get(&key)?);
+            return Ok(());
+        }
+        Some(ConfigCommands::SessionTimeout { minutes }) => {
+            overrides.session_timeout_minutes = Some(minutes);
+        }
+        Some(ConfigCommands::Biometric { state }) => {
+            overrides.enable_biometric = Some(state == "on");
+        }
+        Some(ConfigCommands::Set { key, value }) => {
+            effective.set(&key, &value)?;
+            match key.as_str() {
+                "session_timeout_minutes" => {
+                    overrides.session_timeout_minutes = Some(effective.session_timeout_minutes)
+                }
+                "enable_biometric" => overrides.enable_biometric = Some(effective.enable_biometric),
+                _ => anyhow::bail!("'{}' cannot be overridden per deck", key),
+            }
+        }
+        Some(_) => anyhow::bail!("This setting cannot be overridden per deck"),
+    }
+
+    registry.set_overrides(deck_name, overrides)?;
+    status!("✓ Configuration for deck '{}' updated", deck_name);
+    Ok(())
+}
+
+fn override_marker(overridden: bool) -> &'static str {
+    if overridden {
+        " (deck override)"
+    } else {
+        ""
+    }
+}
@@ -2,13 +2,15 @@ use anyhow::{Context, Result};
 use std::path::Path;
 use std::process::Command;
 
-use crate::domain::SecretResolver;
+use crate::domain::{sanitize_env_key, SecretResolver};
+use crate::handlers::run::read_env_file;
 use crate::infrastructure::KeyringManager;
 use crate::multi_deck_context::MultiDeckContext;
 
 pub struct DealOptions {
     pub uppercase: bool,
     pub prefix: Option<String>,
+    pub env_file: Option<String>,
     pub additional_env: Vec<(String, String)>,
 }
 
@@ -81,12 +83,25 @@ pub fn handle_deal(
     cmd.args(&command[1..]);
 
     for (key, value) in &hand.cards {
-        let env_key = build_env_key(key, &options);
+        let raw_key = build_env_key(key, &options);
+        let env_key = sanitize_env_key(&raw_key);
+        if env_key != raw_key {
+            eprintln!(
+                "⚠ Card '{}' is not a valid variable name; exported as {}",
+                key, env_key
+            );
+        }
         validate_env_key(&env_key)?;
         cmd.env(env_key, value);
     }
 
-    for (key, value) in options.additional_env {
+    let file_vars = match options.env_file.as_deref() {
+        Some(path) => read_env_file(path)?,
+        None => Vec::new(),
+    };
+
+    // Precedence: --env > --env-file > hand cards (later `env` calls win).
+    for (key, value) in file_vars.into_iter().chain(options.additional_env) {
         validate_env_key(&key)?;
         let resolved = resolve_value(&value, deck_name, keyring, config_dir)?;
         cmd.env(key, resolved);
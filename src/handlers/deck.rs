@@ -1,13 +1,26 @@
 use anyhow::{Context, Result};
 use std::collections::HashMap;
+use std::io::{IsTerminal, Read};
 use std::path::Path;
 
 use crate::cli::input;
-use crate::domain::{Hand, PasswordService};
-use crate::handlers::password::copy_to_clipboard_with_clear;
-use crate::infrastructure::{require_biometric_auth, KeyringManager};
+use crate::cli::output::{mask_value, print_payload, status};
+use crate::cli::qr::render_terminal_qr;
+use crate::config::expand_tilde;
+use crate::deck_context::skip_write_for_dry_run;
+use crate::domain::{
+    error::DeckError, glob_match, recovery, validate_name, Deck, Hand, HandSort, HcError,
+    PasswordService, TemplateEngine, TotpService,
+};
+use crate::handlers::password::{
+    clear_notice, copy_to_clipboard_with_clear, read_clipboard, warn_weak_passwords,
+};
+use crate::infrastructure::{require_biometric_auth, write_private_file, KeyringManager};
 use crate::multi_deck_context::MultiDeckContext;
 
+/// Cards on a regular hand that hold a TOTP secret, for `hc hand get --totp`
+const TOTP_CARD_NAMES: &[&str] = &["totp", "otp_secret"];
+
 pub fn handle_init(keyring: &KeyringManager, config_dir: &Path) -> Result<()> {
     println!("⚠️  'hc init' is deprecated.");
     println!("    Use 'hc deck create default' instead.\n");
@@ -18,6 +31,7 @@ pub fn handle_init(keyring: &KeyringManager, config_dir: &Path) -> Result<()> {
             name: "default".to_string(),
         },
         None,
+        false,
         keyring,
         config_dir,
     )
@@ -28,6 +42,9 @@ pub fn handle_add(
     name: Option<String>,
     fields: Vec<(String, String)>,
     file_fields: Vec<(String, String)>,
+    field_stdin: Option<String>,
+    from_clipboard: Option<String>,
+    json: Option<String>,
     generate: bool,
     gen_length: Option<usize>,
     gen_memorable: bool,
@@ -41,19 +58,34 @@ pub fn handle_add(
     keyring: &KeyringManager,
     config_dir: &Path,
 ) -> Result<()> {
+    // stdin carries the card value, so there is nothing left to prompt from
+    let interactive = field_stdin.is_none();
+    let stdin_field = field_stdin
+        .map(|key| read_stdin_value().map(|value| (key, value)))
+        .transpose()?;
+    let clipboard_field = from_clipboard.map(read_clipboard_field).transpose()?;
+    let json_fields = json.as_deref().map(read_json_cards).transpose()?;
+
     let mut ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
 
-    let card_name = name.unwrap_or_else(|| input::prompt_hand_name().unwrap());
+    let card_name = add_hand_name(name, interactive)?;
 
-    let mut custom_fields: HashMap<String, String> = if fields.is_empty() && file_fields.is_empty()
+    let mut custom_fields: HashMap<String, String> = if fields.is_empty()
+        && file_fields.is_empty()
+        && stdin_field.is_none()
+        && clipboard_field.is_none()
+        && json_fields.is_none()
     {
         input::prompt_cards()?
     } else {
-        let mut combined = HashMap::new();
+        let mut combined = json_fields.unwrap_or_default();
         combined.extend(fields);
         combined.extend(file_fields);
+        combined.extend(stdin_field);
+        combined.extend(clipboard_field);
         combined
     };
+    warn_weak_passwords(&custom_fields);
 
     if generate {
         let password = PasswordService::generate_from_cli(
@@ -67,53 +99,127 @@ pub fn handle_add(
         )?;
 
         custom_fields.insert("password".to_string(), password);
-        println!("Generated password for 'password' field (hidden)");
+        status!("Generated password for 'password' field (hidden)");
     }
 
-    let notes = match note {
-        Some(n) => {
-            if n.is_empty() {
-                None
-            } else {
-                Some(n)
-            }
-        }
-        None => input::prompt_notes()?,
-    };
+    let notes = add_hand_notes(note, interactive)?;
 
     let hand = Hand::new(card_name.clone(), custom_fields, notes);
-    ctx.inner
-        .deck
-        .add_hand(hand)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    ctx.inner.deck.add_hand(hand).map_err(HcError::from)?;
 
     ctx.save()?;
 
-    println!("Hand '{}' added successfully!", card_name);
+    status!("Hand '{}' added successfully!", card_name);
     Ok(())
 }
 
+/// The name for `hand add`, prompting only when `interactive`
+fn add_hand_name(name: Option<String>, interactive: bool) -> Result<String> {
+    match name {
+        Some(name) => Ok(name),
+        None if interactive => input::prompt_hand_name(),
+        None => anyhow::bail!("A hand name is required with --field-stdin"),
+    }
+}
+
+/// Notes for `hand add`: an empty `--note` means none, and a missing one
+/// prompts only when `interactive`
+fn add_hand_notes(note: Option<String>, interactive: bool) -> Result<Option<String>> {
+    match note {
+        Some(note) => Ok(Some(note).filter(|n| !n.is_empty())),
+        None if interactive => input::prompt_notes(),
+        None => Ok(None),
+    }
+}
+
+/// Read `{"card": "value", ...}` from a JSON file; every value must be a string
+fn read_json_cards(path: &str) -> Result<HashMap<String, String>> {
+    let content =
+        std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path))?;
+    let object: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(&content).with_context(|| format!("{} is not a JSON object", path))?;
+
+    object
+        .into_iter()
+        .map(|(key, value)| match value {
+            serde_json::Value::String(value) => {
+                validate_name(&key).map_err(HcError::from)?;
+                Ok((key, value))
+            }
+            other => anyhow::bail!(
+                "Card '{}' in {} must be a string, found {}",
+                key,
+                path,
+                json_type_name(&other)
+            ),
+        })
+        .collect()
+}
+
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "a boolean",
+        serde_json::Value::Number(_) => "a number",
+        serde_json::Value::String(_) => "a string",
+        serde_json::Value::Array(_) => "an array",
+        serde_json::Value::Object(_) => "an object",
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn handle_get(
-    name: &str,
+    name: Option<&str>,
     clip: Option<Option<String>>,
+    clip_all: bool,
     totp: bool,
     show: bool,
+    field: Option<&str>,
+    history: Option<&str>,
+    notes: bool,
+    reveal_prefix: usize,
+    qr: Option<String>,
+    out: Vec<(String, String)>,
+    no_newline: bool,
     deck_name: Option<&str>,
     keyring: &KeyringManager,
     config_dir: &Path,
 ) -> Result<()> {
     let ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
 
-    // Require Touch ID for sensitive operations (show or clip)
-    if show || clip.is_some() {
+    // Require Touch ID for sensitive operations (show, reveal, clip or qr)
+    if show
+        || reveal_prefix > 0
+        || clip.is_some()
+        || clip_all
+        || qr.is_some()
+        || !out.is_empty()
+        || history.is_some()
+        || notes
+    {
         require_biometric_auth(&ctx.inner.config, "Access sensitive data")?;
     }
 
-    let card = ctx
-        .inner
-        .deck
-        .get_hand(name)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    let name = &pick_hand_name(&ctx.inner.deck, name)?;
+    let card = ctx.inner.deck.get_hand(name).map_err(HcError::from)?;
+
+    if let Some(card_key) = history {
+        return print_prior_values(card, card_key);
+    }
+    if notes {
+        let notes = card
+            .notes
+            .as_deref()
+            .with_context(|| format!("Hand '{}' has no notes", name))?;
+        return print_payload(notes, !no_newline);
+    }
+    if let Some(field) = field {
+        let value = card
+            .cards
+            .get(field)
+            .with_context(|| format!("Card '{}' not found", field))?;
+        return print_payload(value, !no_newline);
+    }
 
     println!("\nHand: {}", card.name());
     println!("Created: {}", card.created_at.format("%Y-%m-%d %H:%M:%S"));
@@ -121,20 +227,34 @@ pub fn handle_get(
 
     if !card.cards.is_empty() {
         println!("\nCards:");
-        if show {
-            for (key, value) in &card.cards {
+        for (key, value) in &card.cards {
+            if show || ctx.inner.config.is_non_secret(key) {
                 println!("  {}: {}", key, value);
-            }
-        } else {
-            for key in card.cards.keys() {
-                println!("  {}: ******", key);
+            } else {
+                println!("  {}: {}", key, mask_value(value, reveal_prefix));
             }
         }
     }
 
-    if totp && name == "totp" {
+    let totp_code = if totp && name == "totp" {
         println!("\n⚠ Use 'hc totp get <name>' to generate TOTP codes");
-    }
+        None
+    } else if totp {
+        let secret = TOTP_CARD_NAMES
+            .iter()
+            .find_map(|key| card.cards.get(*key))
+            .with_context(|| format!("Hand '{}' has no 'totp' or 'otp_secret' card", name))?;
+        let (secret, params) = TotpService::from_stored_value(secret)?;
+        let code = TotpService::generate_code(&secret, &params)?;
+        println!(
+            "\nTOTP Code: {} (valid for {} seconds)",
+            code,
+            TotpService::get_remaining_seconds(params.period)
+        );
+        Some(code)
+    } else {
+        None
+    };
 
     if let Some(notes) = &card.notes {
         if show {
@@ -145,49 +265,207 @@ pub fn handle_get(
     }
 
     if let Some(card_name) = clip {
-        let value_to_copy = match card_name {
-            Some(card_key) => card
-                .cards
-                .get(&card_key)
-                .context(format!("Card '{}' not found", card_key))?
-                .clone(),
-            None => {
-                if let Some(password) = card.cards.get("password") {
-                    password.clone()
-                } else {
-                    card.cards
-                        .values()
-                        .next()
-                        .context("No cards to copy")?
-                        .clone()
-                }
-            }
+        let value_to_copy = match (&totp_code, &card_name) {
+            (Some(code), None) => code.as_str(),
+            _ => clip_target(card, card_name.as_deref())?.1.as_str(),
         };
 
-        copy_to_clipboard_with_clear(&value_to_copy)?;
-        println!("\nCopied to clipboard (will clear in 30 seconds)");
+        copy_to_clipboard_with_clear(value_to_copy)?;
+        status!("\nCopied to clipboard ({})", clear_notice(true));
+    }
+
+    for (card_key, path) in &out {
+        let value = card
+            .cards
+            .get(card_key)
+            .with_context(|| format!("Card '{}' not found", card_key))?;
+        let path = expand_tilde(path)?;
+        if skip_write_for_dry_run(&path) {
+            continue;
+        }
+        write_private_file(&path, value)?;
+        status!("✓ Wrote '{}' to {}", card_key, path.display());
+    }
+
+    if clip_all {
+        copy_to_clipboard_with_clear(&TemplateEngine::expand_hand(card))?;
+        status!(
+            "\n⚠ Copied all {} cards as KEY=value lines ({})",
+            card.cards.len(),
+            clear_notice(true)
+        );
+    }
+
+    if let Some(card_key) = qr {
+        let value = card
+            .cards
+            .get(&card_key)
+            .with_context(|| format!("Card '{}' not found", card_key))?;
+
+        println!("\n⚠ The value of '{}' is now visible on screen.", card_key);
+        println!("{}", render_terminal_qr(value)?);
     }
 
     Ok(())
 }
 
+/// Resolve `name`, or let the user fuzzy-pick a hand when it is omitted
+fn pick_hand_name(deck: &Deck, name: Option<&str>) -> Result<String> {
+    if let Some(name) = name {
+        return resolve_hand_name(deck, name);
+    }
+    if !std::io::stdin().is_terminal() {
+        anyhow::bail!("Specify a hand name (the picker needs an interactive terminal)");
+    }
+
+    let hands = deck.list_hands();
+    if hands.is_empty() {
+        anyhow::bail!("Deck has no hands");
+    }
+    let names: Vec<&str> = hands.iter().map(|hand| hand.name()).collect();
+    Ok(names[input::prompt_pick_hand(&names)?].to_string())
+}
+
+/// The stored name for `name` (see [`Deck::find_hand_name`]). When nothing
+/// matches, close names are suggested before the not-found error.
+fn resolve_hand_name(deck: &Deck, name: &str) -> Result<String> {
+    if let Some(stored) = deck.find_hand_name(name) {
+        if stored != name {
+            eprintln!("Using hand '{}'", stored);
+        }
+        return Ok(stored.to_string());
+    }
+
+    let similar = deck.similar_hand_names(name);
+    if !similar.is_empty() {
+        eprintln!("Did you mean: {}?", similar.join(", "));
+    }
+    Err(HcError::from(DeckError::HandNotFound(name.to_string())).into())
+}
+
+fn print_prior_values(hand: &Hand, card_key: &str) -> Result<()> {
+    if !hand.track_values {
+        anyhow::bail!(
+            "Value history is off for hand '{}'. Enable it with 'hc hand edit {} --track-values'",
+            hand.name(),
+            hand.name()
+        );
+    }
+
+    let prior = hand
+        .prior_values
+        .get(card_key)
+        .map_or(&[][..], Vec::as_slice);
+    if prior.is_empty() {
+        println!("No prior values recorded for card '{}'.", card_key);
+        return Ok(());
+    }
+
+    println!(
+        "\nPrior values of '{}' in hand '{}':",
+        card_key,
+        hand.name()
+    );
+    for entry in prior.iter().rev() {
+        println!(
+            "  {}  {}",
+            entry.replaced_at.format("%Y-%m-%d %H:%M:%S"),
+            entry.value
+        );
+    }
+    Ok(())
+}
+
+/// Card to copy: the named one, else a prompt when several cards look like
+/// secrets and stdin is a terminal, else `password` or the first card.
+fn clip_target<'a>(hand: &'a Hand, card_key: Option<&str>) -> Result<(&'a str, &'a String)> {
+    let card_key = match card_key {
+        Some(key) => Some(key),
+        None => {
+            let candidates = hand.secret_card_keys();
+            if candidates.len() > 1 && std::io::stdin().is_terminal() {
+                Some(candidates[input::prompt_select_card("Card to copy", &candidates)?])
+            } else {
+                None
+            }
+        }
+    };
+
+    match card_key {
+        Some(key) => hand
+            .cards
+            .get_key_value(key)
+            .map(|(key, value)| (key.as_str(), value))
+            .with_context(|| format!("Card '{}' not found", key)),
+        None => hand.default_card().context("No cards to copy"),
+    }
+}
+
+pub fn handle_clip(
+    name: &str,
+    card_key: Option<&str>,
+    deck_name: Option<&str>,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    let ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+    require_biometric_auth(&ctx.inner.config, "Access sensitive data")?;
+
+    let name = &resolve_hand_name(&ctx.inner.deck, name)?;
+    let hand = ctx.inner.deck.get_hand(name).map_err(HcError::from)?;
+    let (key, value) = clip_target(hand, card_key)?;
+
+    copy_to_clipboard_with_clear(value)?;
+    status!(
+        "✓ Copied '{}' from '{}' ({})",
+        key,
+        name,
+        clear_notice(true)
+    );
+
+    Ok(())
+}
+
 pub fn handle_list(
+    show: bool,
+    sort: HandSort,
+    reverse: bool,
+    assume_yes: bool,
     deck_name: Option<&str>,
     keyring: &KeyringManager,
     config_dir: &Path,
 ) -> Result<()> {
     let ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
-    let hands = ctx.inner.deck.list_hands();
+    let mut hands = ctx.inner.deck.list_hands_sorted(sort);
+    if reverse {
+        hands.reverse();
+    }
 
     if hands.is_empty() {
         println!("No hands found.");
         return Ok(());
     }
 
+    if show {
+        let prompt = format!(
+            "Print every card value of {} hand(s) in clear text?",
+            hands.len()
+        );
+        if !assume_yes && !input::prompt_confirm(&prompt)? {
+            println!("Cancelled.");
+            return Ok(());
+        }
+        require_biometric_auth(&ctx.inner.config, "Show all card values")?;
+    }
+
     println!("\nHands:");
     for hand in hands {
         println!("  • {}", hand.name());
-        if !hand.cards.is_empty() {
+        if show {
+            for (key, value) in &hand.cards {
+                println!("    {}: {}", key, value);
+            }
+        } else if !hand.cards.is_empty() {
             println!(
                 "    Cards: {}",
                 hand.cards
@@ -202,11 +480,55 @@ pub fn handle_list(
     Ok(())
 }
 
+pub fn handle_find(
+    key: &str,
+    value: &str,
+    contains: bool,
+    assume_yes: bool,
+    deck_name: Option<&str>,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    let ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+
+    if !assume_yes
+        && !input::prompt_confirm(&format!("Search the '{}' card values of every hand?", key))?
+    {
+        println!("Cancelled.");
+        return Ok(());
+    }
+    require_biometric_auth(&ctx.inner.config, "Search card values")?;
+
+    let names = ctx.inner.deck.find_hands_by_card(key, value, contains);
+    if names.is_empty() {
+        println!("No hands found.");
+        return Ok(());
+    }
+
+    for name in names {
+        println!("{}", name);
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn handle_edit(
-    name: &str,
-    fields: Vec<(String, String)>,
+    name: Option<&str>,
+    mut fields: Vec<(String, String)>,
     file_fields: Vec<(String, String)>,
+    from_clipboard: Option<String>,
     rm_fields: Vec<String>,
+    generate: bool,
+    gen_length: Option<usize>,
+    gen_memorable: bool,
+    gen_words: Option<usize>,
+    gen_no_uppercase: bool,
+    gen_no_lowercase: bool,
+    gen_no_digits: bool,
+    gen_no_symbols: bool,
+    clip: bool,
+    track_values: bool,
+    no_track_values: bool,
     deck_name: Option<&str>,
     keyring: &KeyringManager,
     config_dir: &Path,
@@ -216,37 +538,78 @@ pub fn handle_edit(
     // Require Touch ID for edit operations
     require_biometric_auth(&ctx.inner.config, "Modify hand")?;
 
-    let card = ctx
-        .inner
-        .deck
-        .get_hand_mut(name)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    let name = &pick_hand_name(&ctx.inner.deck, name)?;
+    fields.extend(from_clipboard.map(read_clipboard_field).transpose()?);
+    let card = ctx.inner.deck.get_hand_mut(name).map_err(HcError::from)?;
+
+    let start_tracking = track_values && !card.track_values;
+    if start_tracking {
+        card.track_values = true;
+        status!("✓ Value history enabled (prior values are kept, encrypted)");
+    }
+    let stop_tracking = no_track_values && (card.track_values || !card.prior_values.is_empty());
+    if stop_tracking {
+        card.stop_tracking_values();
+        status!("✓ Value history disabled and stored prior values deleted");
+    }
+
+    if !fields.is_empty()
+        || !file_fields.is_empty()
+        || !rm_fields.is_empty()
+        || generate
+        || start_tracking
+        || stop_tracking
+    {
+        let previous = card.clone();
+        warn_weak_passwords(fields.iter().map(|(key, value)| (key, value)));
 
-    if !fields.is_empty() || !file_fields.is_empty() || !rm_fields.is_empty() {
         for (key, value) in fields {
             card.cards.insert(key.clone(), value);
-            println!("✓ Card '{}' updated", key);
+            status!("✓ Card '{}' updated", key);
         }
 
         for (key, value) in file_fields {
             card.cards.insert(key.clone(), value);
-            println!("✓ Card '{}' updated from file", key);
+            status!("✓ Card '{}' updated from file", key);
         }
 
         for key in rm_fields {
             if card.cards.remove(&key).is_some() {
-                println!("✓ Card '{}' removed", key);
+                status!("✓ Card '{}' removed", key);
             } else {
-                println!("⚠ Card '{}' not found", key);
+                eprintln!("⚠ Card '{}' not found", key);
             }
         }
 
+        let generated = if generate {
+            let password = PasswordService::generate_from_cli(
+                gen_memorable,
+                gen_words,
+                gen_length,
+                gen_no_uppercase,
+                gen_no_lowercase,
+                gen_no_digits,
+                gen_no_symbols,
+            )?;
+            card.cards.insert("password".to_string(), password.clone());
+            status!("✓ Card 'password' regenerated (hidden)");
+            Some(password)
+        } else {
+            None
+        };
+
+        card.record_changes(&previous);
         card.touch();
         ctx.save()?;
-        println!("✓ Hand '{}' updated successfully!", name);
+        status!("✓ Hand '{}' updated successfully!", name);
+
+        if let (true, Some(password)) = (clip, generated) {
+            copy_to_clipboard_with_clear(&password)?;
+            status!("Password copied to clipboard ({})", clear_notice(true));
+        }
     } else {
         println!(
-            "⚠ No changes specified. Use -f to add/update cards, --file to add from file, or --rm-card to remove cards."
+            "⚠ No changes specified. Use -f to add/update cards, --file to add from file, --rm-card to remove cards, or --generate to rotate the password."
         );
     }
 
@@ -254,52 +617,265 @@ pub fn handle_edit(
 }
 
 pub fn handle_edit_interactive(
-    name: &str,
+    name: Option<&str>,
     deck_name: Option<&str>,
     keyring: &KeyringManager,
     config_dir: &Path,
 ) -> Result<()> {
     let mut ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
 
-    let card = ctx
-        .inner
-        .deck
-        .get_hand_mut(name)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    let name = &pick_hand_name(&ctx.inner.deck, name)?;
+    let card = ctx.inner.deck.get_hand_mut(name).map_err(HcError::from)?;
     println!("Editing hand: {}", card.name());
+    let previous = card.clone();
 
     loop {
         match input::prompt_edit_menu(card)? {
             input::EditAction::Done => break,
             input::EditAction::EditCard(key) => {
                 let value = input::prompt_card_value(&key)?;
+                warn_weak_passwords([(&key, &value)]);
                 card.cards.insert(key.clone(), value);
-                println!("✓ Card '{}' updated", key);
+                status!("✓ Card '{}' updated", key);
             }
             input::EditAction::AddCard => {
                 let (key, value) = input::prompt_new_card()?;
+                warn_weak_passwords([(&key, &value)]);
                 card.cards.insert(key.clone(), value);
-                println!("✓ Card '{}' added", key);
+                status!("✓ Card '{}' added", key);
             }
             input::EditAction::DeleteCard(key) => {
                 if card.cards.remove(&key).is_some() {
-                    println!("✓ Card '{}' removed", key);
+                    status!("✓ Card '{}' removed", key);
                 } else {
-                    println!("⚠ Card '{}' not found", key);
+                    eprintln!("⚠ Card '{}' not found", key);
                 }
             }
             input::EditAction::EditNotes => {
                 let new_notes = input::prompt_notes()?;
                 card.update_notes(new_notes);
-                println!("✓ Notes updated");
+                status!("✓ Notes updated");
             }
         }
     }
 
+    card.record_changes(&previous);
     card.touch();
     ctx.save()?;
 
-    println!("✓ Hand '{}' updated successfully!", name);
+    status!("✓ Hand '{}' updated successfully!", name);
+    Ok(())
+}
+
+pub fn handle_duplicate(
+    name: &str,
+    new_name: Option<String>,
+    deck_name: Option<&str>,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    let mut ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+
+    let name = &resolve_hand_name(&ctx.inner.deck, name)?;
+    let source = ctx.inner.deck.get_hand(name).map_err(HcError::from)?;
+
+    let new_name = match new_name {
+        Some(n) => n,
+        None => input::prompt_hand_name()?,
+    };
+
+    let hand = source.duplicate(new_name.clone());
+    ctx.inner.deck.add_hand(hand).map_err(HcError::from)?;
+
+    ctx.save()?;
+
+    status!("✓ Hand '{}' duplicated as '{}'", name, new_name);
+    Ok(())
+}
+
+pub fn handle_open(
+    name: &str,
+    clip: bool,
+    deck_name: Option<&str>,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    let ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+    let name = &resolve_hand_name(&ctx.inner.deck, name)?;
+    let hand = ctx.inner.deck.get_hand(name).map_err(HcError::from)?;
+
+    let url = ["url", "uri"]
+        .iter()
+        .find_map(|key| hand.cards.get(*key))
+        .with_context(|| format!("Hand '{}' has no 'url' or 'uri' card", name))?;
+
+    if clip {
+        require_biometric_auth(&ctx.inner.config, "Access sensitive data")?;
+        let password = hand
+            .cards
+            .get("password")
+            .with_context(|| format!("Hand '{}' has no 'password' card", name))?;
+        copy_to_clipboard_with_clear(password)?;
+        status!("Password copied to clipboard ({})", clear_notice(true));
+    }
+
+    open_in_browser(url)?;
+    status!("✓ Opened {}", url);
+
+    Ok(())
+}
+
+/// `hand open --clip` for the common case, but a hand without a url still
+/// gets its password copied.
+pub fn handle_login(
+    name: &str,
+    deck_name: Option<&str>,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    let ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+    let name = &resolve_hand_name(&ctx.inner.deck, name)?;
+    let hand = ctx.inner.deck.get_hand(name).map_err(HcError::from)?;
+
+    let password = hand
+        .cards
+        .get("password")
+        .with_context(|| format!("Hand '{}' has no 'password' card", name))?;
+
+    require_biometric_auth(&ctx.inner.config, "Access sensitive data")?;
+    copy_to_clipboard_with_clear(password)?;
+    status!("Password copied to clipboard ({})", clear_notice(true));
+
+    match ["url", "uri"].iter().find_map(|key| hand.cards.get(*key)) {
+        Some(url) => {
+            open_in_browser(url)?;
+            status!("✓ Opened {}", url);
+        }
+        None => status!(
+            "⚠ Hand '{}' has no 'url' or 'uri' card; nothing to open",
+            name
+        ),
+    }
+
+    Ok(())
+}
+
+fn open_in_browser(url: &str) -> Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        std::process::Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        let mut cmd = std::process::Command::new("cmd");
+        cmd.args(["/C", "start", ""]);
+        cmd
+    } else {
+        std::process::Command::new("xdg-open")
+    };
+
+    let status = command
+        .arg(url)
+        .status()
+        .context("Failed to launch the system URL opener")?;
+
+    if !status.success() {
+        anyhow::bail!("URL opener exited with status {}", status);
+    }
+
+    Ok(())
+}
+
+pub fn handle_recovery(
+    name: &str,
+    deck_name: Option<&str>,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    let mut ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+
+    require_biometric_auth(&ctx.inner.config, "Use recovery code")?;
+
+    let name = &resolve_hand_name(&ctx.inner.deck, name)?;
+    let hand = ctx.inner.deck.get_hand_mut(name).map_err(HcError::from)?;
+
+    let codes_value = hand
+        .cards
+        .get(recovery::RECOVERY_CODES_CARD)
+        .with_context(|| {
+            format!(
+                "Hand '{}' has no '{}' card",
+                name,
+                recovery::RECOVERY_CODES_CARD
+            )
+        })?
+        .clone();
+    let codes = recovery::parse_codes(&codes_value);
+    let mut used = hand
+        .cards
+        .get(recovery::RECOVERY_CODES_USED_CARD)
+        .map(|value| recovery::parse_used(value))
+        .unwrap_or_default();
+
+    let (index, code) = recovery::next_unused(&codes, &used)
+        .with_context(|| format!("All recovery codes for '{}' have been used", name))?;
+
+    let previous = hand.clone();
+    used.insert(index);
+    hand.cards.insert(
+        recovery::RECOVERY_CODES_USED_CARD.to_string(),
+        recovery::format_used(&used),
+    );
+    hand.record_changes(&previous);
+    hand.touch();
+
+    let remaining = codes.len() - used.iter().filter(|i| **i < codes.len()).count();
+    let code = code.to_string();
+    ctx.save()?;
+
+    println!("{}", code);
+    status!("✓ Code marked as used ({} remaining)", remaining);
+    if remaining == 0 {
+        eprintln!("⚠ That was the last recovery code. Generate new ones with the service.");
+    }
+
+    Ok(())
+}
+
+pub fn handle_history(
+    name: &str,
+    deck_name: Option<&str>,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    let ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+    let name = &resolve_hand_name(&ctx.inner.deck, name)?;
+    let hand = ctx.inner.deck.get_hand(name).map_err(HcError::from)?;
+
+    if hand.history.is_empty() {
+        println!("No history recorded for hand '{}'.", name);
+        return Ok(());
+    }
+
+    println!("\nHistory for hand '{}':", name);
+    for record in &hand.history {
+        let mut changes = Vec::new();
+        if !record.added.is_empty() {
+            changes.push(format!("added: {}", record.added.join(", ")));
+        }
+        if !record.updated.is_empty() {
+            changes.push(format!("updated: {}", record.updated.join(", ")));
+        }
+        if !record.removed.is_empty() {
+            changes.push(format!("removed: {}", record.removed.join(", ")));
+        }
+        if record.notes_changed {
+            changes.push("notes changed".to_string());
+        }
+        println!(
+            "  {}  {}",
+            record.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            changes.join("; ")
+        );
+    }
+
     Ok(())
 }
 
@@ -314,25 +890,133 @@ pub fn handle_card_rm(
 
     require_biometric_auth(&ctx.inner.config, "Remove card")?;
 
+    let hand_name = &resolve_hand_name(&ctx.inner.deck, hand_name)?;
     let hand = ctx
         .inner
         .deck
         .get_hand_mut(hand_name)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+        .map_err(HcError::from)?;
 
+    let previous = hand.clone();
     if hand.cards.remove(card_key).is_none() {
         anyhow::bail!("Card '{}' not found in hand '{}'", card_key, hand_name);
     }
 
+    hand.record_changes(&previous);
+    hand.touch();
+    ctx.save()?;
+
+    status!("✓ Card '{}' removed from hand '{}'!", card_key, hand_name);
+    Ok(())
+}
+
+pub fn handle_card_mv(
+    hand_name: &str,
+    old_key: &str,
+    new_key: &str,
+    deck_name: Option<&str>,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    let mut ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+
+    let hand_name = &resolve_hand_name(&ctx.inner.deck, hand_name)?;
+    let hand = ctx
+        .inner
+        .deck
+        .get_hand_mut(hand_name)
+        .map_err(HcError::from)?;
+
+    let previous = hand.clone();
+    hand.rename_card(old_key, new_key)
+        .map_err(HcError::from)
+        .with_context(|| format!("Failed to rename card in hand '{}'", hand_name))?;
+
+    hand.record_changes(&previous);
     hand.touch();
     ctx.save()?;
 
-    println!("✓ Card '{}' removed from hand '{}'!", card_key, hand_name);
+    status!(
+        "✓ Card '{}' renamed to '{}' in hand '{}'",
+        old_key,
+        new_key,
+        hand_name
+    );
+    Ok(())
+}
+
+/// Rename card `old_key` to `new_key` in every hand that has it. Hands that
+/// already hold `new_key` are skipped and reported; the deck is saved once.
+pub fn handle_card_mv_all(
+    old_key: &str,
+    new_key: &str,
+    assume_yes: bool,
+    deck_name: Option<&str>,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    validate_name(new_key).map_err(HcError::from)?;
+    if old_key == new_key {
+        anyhow::bail!("Old and new card keys are the same");
+    }
+
+    let mut ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+
+    let (skipped, renaming): (Vec<&Hand>, Vec<&Hand>) = ctx
+        .inner
+        .deck
+        .list_hands()
+        .into_iter()
+        .filter(|h| h.cards.contains_key(old_key))
+        .partition(|h| h.cards.contains_key(new_key));
+    let skipped: Vec<String> = skipped.iter().map(|h| h.name().to_string()).collect();
+    let renaming: Vec<String> = renaming.iter().map(|h| h.name().to_string()).collect();
+
+    for name in &skipped {
+        eprintln!("⚠ Skipping '{}': it already has a '{}' card", name, new_key);
+    }
+    if renaming.is_empty() {
+        anyhow::bail!("No hands have a card '{}' to rename", old_key);
+    }
+
+    println!("\nHands with card '{}':", old_key);
+    for name in &renaming {
+        println!("  • {}", name);
+    }
+
+    if !assume_yes
+        && !input::prompt_confirm(&format!(
+            "Rename '{}' to '{}' in {} hand(s)?",
+            old_key,
+            new_key,
+            renaming.len()
+        ))?
+    {
+        println!("Cancelled.");
+        return Ok(());
+    }
+
+    for name in &renaming {
+        let hand = ctx.inner.deck.get_hand_mut(name).map_err(HcError::from)?;
+        let previous = hand.clone();
+        hand.rename_card(old_key, new_key).map_err(HcError::from)?;
+        hand.record_changes(&previous);
+        hand.touch();
+    }
+
+    ctx.save()?;
+
+    status!(
+        "✓ Card '{}' renamed to '{}' in {} hand(s)",
+        old_key,
+        new_key,
+        renaming.len()
+    );
     Ok(())
 }
 
 pub fn handle_rm(
-    name: &str,
+    name: Option<&str>,
     deck_name: Option<&str>,
     keyring: &KeyringManager,
     config_dir: &Path,
@@ -342,13 +1026,142 @@ pub fn handle_rm(
     // Require Touch ID for remove operations
     require_biometric_auth(&ctx.inner.config, "Delete hand")?;
 
-    ctx.inner
+    // Removal still needs the exact name; only an omitted one is picked
+    let name = &match name {
+        Some(name) => name.to_string(),
+        None => pick_hand_name(&ctx.inner.deck, None)?,
+    };
+    ctx.inner.deck.remove_hand(name).map_err(HcError::from)?;
+
+    ctx.save()?;
+
+    status!("✓ Hand '{}' removed successfully!", name);
+    Ok(())
+}
+
+pub fn handle_rm_matching(
+    pattern: &str,
+    assume_yes: bool,
+    deck_name: Option<&str>,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    let mut ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+
+    let matching: Vec<String> = ctx
+        .inner
         .deck
-        .remove_hand(name)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+        .list_hands()
+        .iter()
+        .filter(|h| glob_match(pattern, h.name()))
+        .map(|h| h.name().to_string())
+        .collect();
+
+    if matching.is_empty() {
+        anyhow::bail!("No hands match '{}'", pattern);
+    }
+
+    println!("\nHands matching '{}':", pattern);
+    for name in &matching {
+        println!("  • {}", name);
+    }
+
+    if !assume_yes && !input::prompt_confirm(&format!("Remove {} hand(s)?", matching.len()))? {
+        println!("Cancelled.");
+        return Ok(());
+    }
+
+    require_biometric_auth(&ctx.inner.config, "Delete hands")?;
+
+    for name in &matching {
+        ctx.inner.deck.remove_hand(name).map_err(HcError::from)?;
+    }
 
     ctx.save()?;
 
-    println!("✓ Hand '{}' removed successfully!", name);
+    status!("✓ Removed {} hand(s)", matching.len());
     Ok(())
 }
+
+/// Read `key`'s value from the clipboard, warning that it will be stored
+fn read_clipboard_field(key: String) -> Result<(String, String)> {
+    let value = read_clipboard()?;
+    eprintln!("⚠ Storing clipboard contents in card '{}'", key);
+    Ok((key, value))
+}
+
+fn read_stdin_value() -> Result<String> {
+    let mut value = String::new();
+    std::io::stdin()
+        .read_to_string(&mut value)
+        .context("Failed to read card value from stdin")?;
+
+    if value.ends_with('\n') {
+        value.pop();
+        if value.ends_with('\r') {
+            value.pop();
+        }
+    }
+
+    if value.is_empty() {
+        anyhow::bail!("No value received on stdin");
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::error::DeckError;
+
+    fn cards_from(json: &str) -> Result<HashMap<String, String>> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cards.json");
+        std::fs::write(&path, json).unwrap();
+        read_json_cards(path.to_str().unwrap())
+    }
+
+    #[test]
+    fn test_add_with_field_stdin_never_prompts() {
+        assert!(add_hand_name(None, false).is_err());
+        assert_eq!(add_hand_name(Some("myapp".into()), false).unwrap(), "myapp");
+        assert_eq!(add_hand_notes(None, false).unwrap(), None);
+        assert_eq!(add_hand_notes(Some(String::new()), false).unwrap(), None);
+        assert_eq!(
+            add_hand_notes(Some("prod".into()), false)
+                .unwrap()
+                .as_deref(),
+            Some("prod")
+        );
+    }
+
+    #[test]
+    fn test_read_json_cards() {
+        let cards = cards_from(r#"{"username": "alice", "password": "s3cret"}"#).unwrap();
+        assert_eq!(cards.len(), 2);
+        assert_eq!(cards["password"], "s3cret");
+    }
+
+    #[test]
+    fn test_read_json_cards_rejects_non_object() {
+        let err = cards_from(r#"["alice", "s3cret"]"#).unwrap_err();
+        assert!(err.to_string().contains("is not a JSON object"));
+    }
+
+    #[test]
+    fn test_read_json_cards_rejects_non_string_values() {
+        let err = cards_from(r#"{"username": "alice", "port": 22}"#).unwrap_err();
+        assert!(err.to_string().contains("Card 'port'"));
+        assert!(err.to_string().contains("found a number"));
+    }
+
+    #[test]
+    fn test_read_json_cards_rejects_invalid_keys() {
+        let err = cards_from(r#"{"bad\u0007key": "value"}"#).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<HcError>(),
+            Some(HcError::Deck(DeckError::InvalidName(_)))
+        ));
+    }
+}
@@ -1,11 +1,15 @@
 use anyhow::{Context, Result};
 use rand::RngCore;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use zeroize::Zeroizing;
 
 use crate::cli::commands::DeckCommands;
-use crate::domain::CryptoService;
+use crate::cli::output::status;
+use crate::domain::{CryptoService, HcError, MergeOutcome, MergeStrategy};
+use crate::handlers::ssh::is_ssh_hand;
 use crate::infrastructure::{
-    CryptoServiceImpl, DeckRegistry, DeckStorage, KeyringManager, SessionManager,
+    CryptoServiceImpl, DeckLock, DeckRegistry, DeckStorage, KeyringManager, SessionManager,
+    LOCAL_DECK_FILE,
 };
 use crate::multi_deck_context::MultiDeckContext;
 use crate::{cli::input, config::Config, domain::Deck};
@@ -13,17 +17,59 @@ use crate::{cli::input, config::Config, domain::Deck};
 pub fn handle_deck(
     subcommand: DeckCommands,
     deck_name: Option<&str>,
+    assume_yes: bool,
     keyring: &KeyringManager,
     config_dir: &Path,
 ) -> Result<()> {
     match subcommand {
         DeckCommands::List => handle_list(config_dir),
+        DeckCommands::Info { name } => handle_info(&name, keyring, config_dir),
         DeckCommands::Create { name } => handle_create(name, keyring, config_dir),
-        DeckCommands::Delete { name, force } => handle_delete(name, force, config_dir),
-        DeckCommands::Use { name } => handle_use(name, config_dir),
+        DeckCommands::Delete { name, force } => {
+            handle_delete(name, force || assume_yes, config_dir)
+        }
+        DeckCommands::Use { name, local } => handle_use(name, local, config_dir),
+        DeckCommands::Rename { old_name, new_name } => {
+            handle_rename(&old_name, &new_name, keyring, config_dir)
+        }
         DeckCommands::Move { card, to_hand } => handle_move(card, to_hand, keyring, config_dir),
         DeckCommands::Copy { card, to_hand } => handle_copy(card, to_hand, keyring, config_dir),
-        DeckCommands::Passwd => handle_passwd(deck_name, keyring, config_dir),
+        DeckCommands::Merge {
+            source,
+            target,
+            strategy,
+            delete_source,
+        } => handle_merge(
+            &source,
+            &target,
+            strategy,
+            delete_source,
+            assume_yes,
+            keyring,
+            config_dir,
+        ),
+        DeckCommands::Passwd { rotate_secret_key } => {
+            handle_passwd(deck_name, rotate_secret_key, keyring, config_dir)
+        }
+        DeckCommands::Stats => handle_stats(deck_name, keyring, config_dir),
+        DeckCommands::Compact { new_salt } => {
+            handle_compact(deck_name, new_salt, keyring, config_dir)
+        }
+        DeckCommands::ExportAll { file } => {
+            crate::handlers::transfer::handle_export_all(&file, keyring, config_dir)
+        }
+        DeckCommands::ImportAll {
+            file,
+            overwrite,
+            strategy,
+        } => {
+            let strategy = strategy.unwrap_or(if overwrite {
+                MergeStrategy::Overwrite
+            } else {
+                MergeStrategy::Skip
+            });
+            crate::handlers::transfer::handle_import_all(&file, strategy, keyring, config_dir)
+        }
     }
 }
 
@@ -56,21 +102,59 @@ fn handle_list(config_dir: &Path) -> Result<()> {
     Ok(())
 }
 
-fn handle_create(name: String, keyring: &KeyringManager, config_dir: &Path) -> Result<()> {
+fn handle_info(name: &str, keyring: &KeyringManager, config_dir: &Path) -> Result<()> {
+    let registry = DeckRegistry::load(config_dir)?;
+    let deck = registry.get_deck(name)?;
+    let config = Config::load_for_deck(config_dir, name)?;
+    let session = SessionManager::new(config_dir, name, config.session_timeout_minutes);
+    let session_active = session.is_active();
+
+    println!("\nDeck: {}", deck.name);
+    println!("  Path: {}", deck.path.display());
+    match std::fs::metadata(&deck.path) {
+        Ok(meta) => println!("  Size: {} bytes", meta.len()),
+        Err(_) => println!("  Size: - (file not created yet)"),
+    }
+    println!("  Created: {}", deck.created_at.format("%Y-%m-%d %H:%M:%S"));
+    println!(
+        "  Last accessed: {}",
+        deck.last_accessed.format("%Y-%m-%d %H:%M:%S")
+    );
+    println!(
+        "  Session: {}",
+        if session_active { "active" } else { "locked" }
+    );
+
+    // Only count hands when the cached session key can decrypt without a prompt.
+    if session_active {
+        let ctx = MultiDeckContext::load(Some(name), keyring, config_dir)?;
+        println!("  Hands: {}", ctx.inner.deck.list_hands().len());
+    }
+
+    Ok(())
+}
+
+pub fn handle_create(name: String, keyring: &KeyringManager, config_dir: &Path) -> Result<()> {
     let registry = DeckRegistry::load(config_dir)?;
 
     println!("========================================");
     println!("     Creating Deck: {}", name);
     println!("========================================");
     println!("\nPlease set your Master Password.");
+    let policy = Config::load(config_dir)?.master_password_policy;
     println!("Requirements:");
-    println!("  • At least 12 characters");
+    for requirement in policy.requirements() {
+        println!("  • {}", requirement);
+    }
     println!("  • This will be needed to access your deck");
     println!("========================================\n");
 
-    let master_password = input::prompt_master_password_confirm()?;
+    let master_password = input::prompt_master_password_confirm(&policy)?;
 
-    let deck_path = config_dir.join(format!("{}.enc", name));
+    let decks_dir = Config::load(config_dir)?.decks_dir(config_dir);
+    std::fs::create_dir_all(&decks_dir)
+        .with_context(|| format!("Failed to create deck directory: {}", decks_dir.display()))?;
+    let deck_path = decks_dir.join(format!("{}.enc", name));
 
     if deck_path.exists() {
         anyhow::bail!("Deck file already exists at: {}", deck_path.display());
@@ -92,11 +176,11 @@ fn handle_create(name: String, keyring: &KeyringManager, config_dir: &Path) -> R
 
     let (derived_key, salt) = storage
         .derive_key_from_deck(&deck_path, &master_password, &secret_key)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+        .map_err(HcError::from)?;
 
     storage
         .save_with_cached_key(&deck, &deck_path, &derived_key, &salt)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+        .map_err(HcError::from)?;
 
     registry.create_deck(&name, deck_path)?;
 
@@ -104,16 +188,51 @@ fn handle_create(name: String, keyring: &KeyringManager, config_dir: &Path) -> R
     let session = SessionManager::new(config_dir, &name, config.session_timeout_minutes);
     session.save_session(&derived_key, &salt, Vec::new())?;
 
-    println!("\n========================================");
-    println!("     Deck '{}' Created Successfully", name);
-    println!("========================================");
-    println!("\n✓ Master password set");
-    println!("✓ Secret key stored in system keyring");
-    println!("\nIMPORTANT:");
-    println!("  • Use 'hc export' regularly to backup your deck");
-    println!("  • Keep your export file and password safe");
-    println!("  • You need BOTH the export file and its password to restore");
-    println!("========================================\n");
+    status!("\n========================================");
+    status!("     Deck '{}' Created Successfully", name);
+    status!("========================================");
+    status!("\n✓ Master password set");
+    status!("✓ Secret key stored in system keyring");
+    status!("\nIMPORTANT:");
+    status!("  • Use 'hc export' regularly to backup your deck");
+    status!("  • Keep your export file and password safe");
+    status!("  • You need BOTH the export file and its password to restore");
+    status!("========================================\n");
+
+    Ok(())
+}
+
+fn handle_rename(
+    old_name: &str,
+    new_name: &str,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    if new_name.is_empty() || new_name.contains(['/', '\\']) || new_name.starts_with('.') {
+        anyhow::bail!("Invalid deck name: '{}'", new_name);
+    }
+
+    let registry = DeckRegistry::load(config_dir)?;
+    let deck = registry.rename_deck(old_name, new_name)?;
+
+    // Carry the cached session over so the rename doesn't force a re-unlock.
+    let config = Config::load(config_dir)?;
+    let old_session = SessionManager::new(config_dir, old_name, config.session_timeout_minutes);
+    let new_session = SessionManager::new(config_dir, new_name, config.session_timeout_minutes);
+    if let Ok(Some(data)) = old_session.load_session() {
+        new_session.save_session(&data.derived_key, &data.salt, data.hand_names)?;
+    }
+    let _ = old_session.clear_session();
+    let _ = old_session.clear_names_cache();
+
+    // Move the biometric-unlock password to the new account label.
+    if let Some(password) = keyring.load_master_password(old_name)? {
+        keyring.save_master_password(new_name, &password)?;
+        keyring.delete_master_password(old_name)?;
+    }
+
+    status!("✓ Deck '{}' renamed to '{}'", old_name, new_name);
+    status!("  Path: {}", deck.path.display());
 
     Ok(())
 }
@@ -144,22 +263,31 @@ fn handle_delete(name: String, force: bool, config_dir: &Path) -> Result<()> {
     let config = Config::load(config_dir)?;
     let session = SessionManager::new(config_dir, &name, config.session_timeout_minutes);
     let _ = session.clear_session();
+    let _ = session.clear_names_cache();
 
     registry.delete_deck(&name)?;
 
-    println!("✓ Deck '{}' deleted successfully", name);
+    status!("✓ Deck '{}' deleted successfully", name);
 
     Ok(())
 }
 
-fn handle_use(name: String, config_dir: &Path) -> Result<()> {
+fn handle_use(name: String, local: bool, config_dir: &Path) -> Result<()> {
     let registry = DeckRegistry::load(config_dir)?;
 
     registry.get_deck(&name)?;
 
+    if local {
+        let marker = std::env::current_dir()?.join(LOCAL_DECK_FILE);
+        std::fs::write(&marker, format!("{}\n", name))
+            .with_context(|| format!("Failed to write {}", marker.display()))?;
+        status!("✓ Deck '{}' selected for {}", name, marker.display());
+        return Ok(());
+    }
+
     registry.set_active(&name)?;
 
-    println!("✓ Active deck set to '{}'", name);
+    status!("✓ Active deck set to '{}'", name);
 
     Ok(())
 }
@@ -181,7 +309,7 @@ fn handle_move(
         .inner
         .deck
         .get_hand(&hand_name)
-        .map_err(|e| anyhow::anyhow!("{}", e))?
+        .map_err(HcError::from)?
         .clone();
 
     // Load target first to ensure it exists and is accessible
@@ -192,7 +320,7 @@ fn handle_move(
         .inner
         .deck
         .add_hand(hand.clone())
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+        .map_err(HcError::from)?;
 
     target_ctx.save().context("Failed to save to target deck")?;
 
@@ -201,7 +329,7 @@ fn handle_move(
         .inner
         .deck
         .remove_hand(&hand_name)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+        .map_err(HcError::from)?;
 
     // If source save fails, attempt rollback
     if let Err(e) = source_ctx.save() {
@@ -211,9 +339,11 @@ fn handle_move(
         return Err(e).context("Failed to save source deck after move");
     }
 
-    println!(
+    status!(
         "✓ Hand '{}' moved from deck '{}' to deck '{}'",
-        hand_name, source_deck_name, to_deck
+        hand_name,
+        source_deck_name,
+        to_deck
     );
 
     Ok(())
@@ -236,7 +366,7 @@ fn handle_copy(
         .inner
         .deck
         .get_hand(&hand_name)
-        .map_err(|e| anyhow::anyhow!("{}", e))?
+        .map_err(HcError::from)?
         .clone();
 
     let mut target_ctx = MultiDeckContext::load(Some(&to_deck), keyring, config_dir)?;
@@ -245,25 +375,139 @@ fn handle_copy(
         .inner
         .deck
         .add_hand(hand)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+        .map_err(HcError::from)?;
 
     target_ctx.save()?;
 
-    println!(
+    status!(
         "✓ Hand '{}' copied from deck '{}' to deck '{}'",
-        hand_name, source_deck_name, to_deck
+        hand_name,
+        source_deck_name,
+        to_deck
     );
 
     Ok(())
 }
 
+fn handle_merge(
+    source: &str,
+    target: &str,
+    strategy: MergeStrategy,
+    delete_source: bool,
+    assume_yes: bool,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    if source == target {
+        anyhow::bail!("Source and target deck are the same");
+    }
+
+    let source_ctx = MultiDeckContext::load(Some(source), keyring, config_dir)?;
+    let mut target_ctx = MultiDeckContext::load(Some(target), keyring, config_dir)?;
+
+    let mut added = 0;
+    let mut overwritten = 0;
+    let mut merged = 0;
+    let mut skipped = 0;
+    for hand in source_ctx.inner.deck.list_hands() {
+        match target_ctx.inner.deck.merge_hand(hand.clone(), strategy) {
+            MergeOutcome::Added => added += 1,
+            MergeOutcome::Overwritten => overwritten += 1,
+            MergeOutcome::Merged => merged += 1,
+            MergeOutcome::KeptExisting | MergeOutcome::Skipped => {
+                println!("  Skipped '{}'", hand.name());
+                skipped += 1;
+            }
+        }
+    }
+
+    // Target is saved before the source is touched (same order as move)
+    target_ctx.save().context("Failed to save target deck")?;
+
+    status!("✓ Merged deck '{}' into '{}':", source, target);
+    println!("  • {} hands added", added);
+    println!("  • {} hands overwritten", overwritten);
+    println!("  • {} hands merged", merged);
+    println!("  • {} hands skipped", skipped);
+
+    if delete_source {
+        if skipped > 0 && strategy == MergeStrategy::Skip {
+            println!(
+                "⚠ Keeping deck '{}': {} hand(s) were not merged",
+                source, skipped
+            );
+            return Ok(());
+        }
+        drop(source_ctx);
+        handle_delete(source.to_string(), assume_yes, config_dir)?;
+    }
+
+    Ok(())
+}
+
+fn handle_stats(
+    deck_name: Option<&str>,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    let ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+    let hands = ctx.inner.deck.list_hands();
+
+    let total_cards: usize = hands.iter().map(|h| h.cards.len()).sum();
+    let totp_secrets = hands
+        .iter()
+        .find(|h| h.name() == "totp")
+        .map(|h| h.cards.len())
+        .unwrap_or(0);
+    let ssh_hands = hands.iter().filter(|h| is_ssh_hand(h)).count();
+    let with_notes = hands.iter().filter(|h| h.notes.is_some()).count();
+    let oldest = hands.iter().min_by_key(|h| h.updated_at);
+    let newest = hands.iter().max_by_key(|h| h.updated_at);
+
+    println!("\nDeck: {}", ctx.deck_name);
+    println!("  Hands: {}", hands.len());
+    println!("  Cards: {}", total_cards);
+    println!("  TOTP secrets: {}", totp_secrets);
+    println!("  SSH hands: {}", ssh_hands);
+    println!("  Hands with notes: {}", with_notes);
+
+    if let (Some(oldest), Some(newest)) = (oldest, newest) {
+        println!(
+            "  Least recently updated: {} ({})",
+            oldest.name(),
+            oldest.updated_at.format("%Y-%m-%d %H:%M:%S")
+        );
+        println!(
+            "  Most recently updated: {} ({})",
+            newest.name(),
+            newest.updated_at.format("%Y-%m-%d %H:%M:%S")
+        );
+    }
+
+    Ok(())
+}
+
+/// A deck re-encrypted under a new master password and/or secret key
+struct Rekeyed {
+    name: String,
+    path: PathBuf,
+    deck: Deck,
+    derived_key: [u8; 32],
+    salt: [u8; 16],
+}
+
 fn handle_passwd(
     deck_name: Option<&str>,
+    rotate_secret_key: bool,
     keyring: &KeyringManager,
     config_dir: &Path,
 ) -> Result<()> {
     println!("\n========================================");
-    println!("     Change Master Password");
+    if rotate_secret_key {
+        println!("  Change Master Password and Secret Key");
+    } else {
+        println!("     Change Master Password");
+    }
     println!("========================================");
     println!("\nFirst, verify your current master password:");
 
@@ -272,58 +516,263 @@ fn handle_passwd(
 
     let registry = DeckRegistry::load(config_dir)?;
     let deck_path = registry.get_deck(&deck_name_str)?.path.clone();
+    let _lock = ctx.inner.lock()?;
 
-    let backup_path = deck_path.with_extension("enc.backup");
-    std::fs::copy(&deck_path, &backup_path).context("Failed to create deck backup")?;
+    let crypto = CryptoServiceImpl::new();
+    let old_secret_key = keyring.load_secret_key()?;
+    let secret_key = if rotate_secret_key {
+        crypto.generate_secret_key()
+    } else {
+        old_secret_key.clone()
+    };
+
+    // The secret key is shared by every deck, so rotating it re-encrypts
+    // all of them; each one's master password is needed to do that.
+    let mut rekeyed = Vec::new();
+    let mut _other_locks = Vec::new();
+    if rotate_secret_key {
+        for meta in registry.list_decks()? {
+            if meta.name == deck_name_str || !meta.path.exists() {
+                continue;
+            }
+            _other_locks.push(DeckLock::acquire(&meta.path)?);
+            println!("\nMaster password for deck '{}':", meta.name);
+            rekeyed.push(rekey_other_deck(
+                &meta.name,
+                &meta.path,
+                &old_secret_key,
+                &secret_key,
+            )?);
+        }
+    }
 
     println!("\n========================================");
     println!("     Set New Master Password");
     println!("========================================");
     println!("\nEnter new master password:");
-    let new_password = input::prompt_master_password_confirm()?;
+    let policy = Config::load(config_dir)?.master_password_policy;
+    let new_password = input::prompt_master_password_confirm(&policy)?;
 
     let mut salt = [0u8; 16];
     rand::rngs::OsRng.fill_bytes(&mut salt);
-
-    let secret_key = keyring.load_secret_key()?;
-    let crypto = CryptoServiceImpl::new();
     let derived_key = crypto
         .derive_key(&new_password, &secret_key, &salt)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
-
-    if let Err(e) =
-        ctx.inner
-            .storage
-            .save_with_cached_key(&ctx.inner.deck, &deck_path, &derived_key, &salt)
-    {
-        std::fs::rename(&backup_path, &deck_path).context("Failed to restore deck backup")?;
-        anyhow::bail!(
-            "Failed to re-encrypt deck: {}. Deck restored from backup.",
-            e
+        .map_err(HcError::from)?;
+    rekeyed.push(Rekeyed {
+        name: deck_name_str.clone(),
+        path: deck_path,
+        deck: ctx.inner.deck.clone(),
+        derived_key,
+        salt,
+    });
+
+    let backups = write_rekeyed(&ctx.inner.storage, &rekeyed)?;
+
+    if rotate_secret_key {
+        if let Err(e) = keyring.save_secret_key(&secret_key) {
+            restore_backups(&backups)?;
+            anyhow::bail!(
+                "Failed to store the new secret key: {}. Decks restored from backup.",
+                e
+            );
+        }
+    }
+
+    let mut session_result = Ok(());
+    for deck in &rekeyed {
+        let config = Config::load_for_deck(config_dir, &deck.name)?;
+        let session = SessionManager::new(config_dir, &deck.name, config.session_timeout_minutes);
+        let hand_names: Vec<String> = deck
+            .deck
+            .list_hands()
+            .iter()
+            .map(|e| e.name().to_string())
+            .collect();
+
+        let clear_result = session.clear_session();
+        let save_result = session.save_session(&deck.derived_key, &deck.salt, hand_names);
+        session_result = session_result.and(clear_result).and(save_result);
+    }
+    for (_, backup_path) in &backups {
+        std::fs::remove_file(backup_path).ok();
+    }
+    session_result?;
+
+    status!("\n✓ Master password changed successfully");
+    if rotate_secret_key {
+        status!(
+            "✓ Secret key rotated; {} deck(s) re-encrypted",
+            rekeyed.len()
         );
     }
+    status!("✓ Session renewed");
+    status!("\nNext deck access will use the new password.");
 
-    let hand_names: Vec<String> = ctx
-        .inner
-        .deck
-        .list_hands()
-        .iter()
-        .map(|e| e.name().to_string())
-        .collect();
+    Ok(())
+}
 
-    let config = Config::load(config_dir)?;
-    let session = SessionManager::new(config_dir, &deck_name_str, config.session_timeout_minutes);
+/// Verify another deck's master password against the old secret key and
+/// derive its key under the new one (same password, fresh salt).
+fn rekey_other_deck(
+    name: &str,
+    path: &Path,
+    old_secret_key: &str,
+    new_secret_key: &str,
+) -> Result<Rekeyed> {
+    let password = Zeroizing::new(input::prompt_master_password()?);
+    let storage = DeckStorage::new(CryptoServiceImpl::new());
+    let (old_key, _) = storage
+        .derive_key_from_deck(path, &password, old_secret_key)
+        .map_err(HcError::from)?;
+    let deck = storage
+        .load_with_cached_key(path, &old_key)
+        .map_err(HcError::from)
+        .with_context(|| format!("Failed to unlock deck '{}'", name))?;
+
+    let mut salt = [0u8; 16];
+    rand::rngs::OsRng.fill_bytes(&mut salt);
+    let derived_key = CryptoServiceImpl::new()
+        .derive_key(&password, new_secret_key, &salt)
+        .map_err(HcError::from)?;
+
+    Ok(Rekeyed {
+        name: name.to_string(),
+        path: path.to_path_buf(),
+        deck,
+        derived_key,
+        salt,
+    })
+}
+
+/// Back up and rewrite every deck, restoring all of them if any write
+/// fails. Returns the `(deck, backup)` paths for the caller to clean up.
+fn write_rekeyed(
+    storage: &DeckStorage<CryptoServiceImpl>,
+    rekeyed: &[Rekeyed],
+) -> Result<Vec<(PathBuf, PathBuf)>> {
+    let mut backups = Vec::new();
+    for deck in rekeyed {
+        let backup_path = deck.path.with_extension("enc.backup");
+        if let Err(e) = std::fs::copy(&deck.path, &backup_path) {
+            restore_backups(&backups)?;
+            return Err(e).context("Failed to create deck backup");
+        }
+        backups.push((deck.path.clone(), backup_path));
+    }
+
+    for deck in rekeyed {
+        if let Err(e) =
+            storage.save_with_cached_key(&deck.deck, &deck.path, &deck.derived_key, &deck.salt)
+        {
+            restore_backups(&backups)?;
+            anyhow::bail!(
+                "Failed to re-encrypt deck '{}': {}. Deck restored from backup.",
+                deck.name,
+                e
+            );
+        }
+    }
+    Ok(backups)
+}
+
+fn restore_backups(backups: &[(PathBuf, PathBuf)]) -> Result<()> {
+    for (deck_path, backup_path) in backups {
+        std::fs::rename(backup_path, deck_path).context("Failed to restore deck backup")?;
+    }
+    Ok(())
+}
+
+fn handle_compact(
+    deck_name: Option<&str>,
+    new_salt: bool,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    let ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+    let deck_name_str = ctx.deck_name.clone();
+
+    let registry = DeckRegistry::load(config_dir)?;
+    let deck_path = registry.get_deck(&deck_name_str)?.path;
+
+    let (derived_key, salt) = if new_salt {
+        println!("Enter master password to rotate the salt:");
+        let password = input::prompt_master_password()?;
+        let secret_key = keyring.load_secret_key()?;
+        let crypto = CryptoServiceImpl::new();
+
+        let current = crypto
+            .derive_key(&password, &secret_key, &ctx.inner.session_data.salt)
+            .map_err(HcError::from)?;
+        if current != ctx.inner.session_data.derived_key {
+            return Err(HcError::from(crate::domain::CryptoError::IncorrectPassword).into());
+        }
 
-    let clear_result = session.clear_session();
-    let save_result = session.save_session(&derived_key, &salt, hand_names);
+        let mut salt = [0u8; 16];
+        rand::rngs::OsRng.fill_bytes(&mut salt);
+        let derived_key = crypto
+            .derive_key(&password, &secret_key, &salt)
+            .map_err(HcError::from)?;
+        (derived_key, salt)
+    } else {
+        (
+            ctx.inner.session_data.derived_key,
+            ctx.inner.session_data.salt,
+        )
+    };
+
+    let _lock = ctx.inner.lock()?;
+    let size_before = std::fs::metadata(&deck_path).map(|m| m.len()).ok();
+    let backup_path = deck_path.with_extension("enc.backup");
+    std::fs::copy(&deck_path, &backup_path).context("Failed to create deck backup")?;
+
+    // Re-encrypt, then decrypt the new file and compare before trusting it.
+    let verified = ctx
+        .inner
+        .storage
+        .save_with_cached_key(&ctx.inner.deck, &deck_path, &derived_key, &salt)
+        .and_then(|_| {
+            ctx.inner
+                .storage
+                .load_with_cached_key(&deck_path, &derived_key)
+        })
+        .map(|rewritten| {
+            serde_json::to_value(&rewritten).ok() == serde_json::to_value(&ctx.inner.deck).ok()
+        });
+
+    match verified {
+        Ok(true) => {}
+        Ok(false) => {
+            std::fs::rename(&backup_path, &deck_path).context("Failed to restore deck backup")?;
+            anyhow::bail!("Rewritten deck did not match the original. Deck restored from backup.");
+        }
+        Err(e) => {
+            std::fs::rename(&backup_path, &deck_path).context("Failed to restore deck backup")?;
+            anyhow::bail!("Failed to rewrite deck: {}. Deck restored from backup.", e);
+        }
+    }
     std::fs::remove_file(&backup_path).ok();
 
-    clear_result?;
-    save_result?;
+    if new_salt {
+        let hand_names: Vec<String> = ctx
+            .inner
+            .deck
+            .list_hands()
+            .iter()
+            .map(|e| e.name().to_string())
+            .collect();
+        let config = Config::load_for_deck(config_dir, &deck_name_str)?;
+        let session =
+            SessionManager::new(config_dir, &deck_name_str, config.session_timeout_minutes);
+        session.save_session(&derived_key, &salt, hand_names)?;
+    }
 
-    println!("\n✓ Master password changed successfully");
-    println!("✓ Session renewed");
-    println!("\nNext deck access will use the new password.");
+    status!("✓ Deck '{}' compacted", deck_name_str);
+    if let (Some(before), Ok(after)) = (size_before, std::fs::metadata(&deck_path)) {
+        status!("  Size: {} → {} bytes", before, after.len());
+    }
+    if new_salt {
+        status!("✓ Salt rotated and session renewed");
+    }
 
     Ok(())
 }
@@ -0,0 +1,216 @@
+use anyhow::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::handlers::ssh::is_sshpass_available;
+use crate::infrastructure::{DeckRegistry, KeyringManager, SshAgent};
+
+enum Level {
+    Pass,
+    Warn,
+    Fail,
+}
+
+struct Check {
+    level: Level,
+    message: String,
+    hint: Option<String>,
+}
+
+impl Check {
+    fn pass(message: impl Into<String>) -> Self {
+        Self {
+            level: Level::Pass,
+            message: message.into(),
+            hint: None,
+        }
+    }
+
+    fn warn(message: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            level: Level::Warn,
+            message: message.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn fail(message: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            level: Level::Fail,
+            message: message.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+pub fn handle_doctor(keyring: &KeyringManager, config_dir: &Path) -> Result<()> {
+    let mut checks = vec![check_config_dir(config_dir), check_config(config_dir)];
+    checks.push(check_secret_key(keyring));
+    checks.extend(check_decks(config_dir));
+    checks.push(check_ssh_agent());
+    checks.push(check_sshpass());
+
+    let mut failures = 0;
+    let mut warnings = 0;
+    for check in &checks {
+        let icon = match check.level {
+            Level::Pass => "✓",
+            Level::Warn => {
+                warnings += 1;
+                "⚠"
+            }
+            Level::Fail => {
+                failures += 1;
+                "✗"
+            }
+        };
+        println!("{} {}", icon, check.message);
+        if let Some(hint) = &check.hint {
+            println!("    → {}", hint);
+        }
+    }
+
+    println!(
+        "\n{} passed, {} warning(s), {} failure(s)",
+        checks.len() - warnings - failures,
+        warnings,
+        failures
+    );
+
+    if failures > 0 {
+        anyhow::bail!("{} check(s) failed", failures);
+    }
+    Ok(())
+}
+
+fn check_config_dir(config_dir: &Path) -> Check {
+    let probe = config_dir.join(".hc-doctor-probe");
+    match fs::write(&probe, b"ok").and_then(|_| fs::remove_file(&probe)) {
+        Ok(()) => Check::pass(format!(
+            "Config directory is writable: {}",
+            config_dir.display()
+        )),
+        Err(e) => Check::fail(
+            format!(
+                "Config directory is not writable: {} ({})",
+                config_dir.display(),
+                e
+            ),
+            "Fix the directory's ownership/permissions (it should belong to you, mode 700)",
+        ),
+    }
+}
+
+fn check_config(config_dir: &Path) -> Check {
+    match Config::load(config_dir) {
+        Ok(_) => Check::pass("config.toml is valid"),
+        Err(e) => Check::fail(
+            format!("config.toml could not be loaded: {:#}", e),
+            "Fix the file by hand, or move it aside to start from the defaults",
+        ),
+    }
+}
+
+fn check_secret_key(keyring: &KeyringManager) -> Check {
+    match keyring.load_secret_key() {
+        Ok(_) if keyring.uses_fallback_file() => Check::warn(
+            "Secret key is stored in a fallback file, not the OS keyring",
+            "Make sure a keyring service (Keychain, Secret Service, Credential Manager) is running",
+        ),
+        Ok(_) => Check::pass("Secret key found in the OS keyring"),
+        Err(_) => Check::fail(
+            "Secret key not found",
+            "Run 'hc init', or restore the secret key from your backup",
+        ),
+    }
+}
+
+fn check_decks(config_dir: &Path) -> Vec<Check> {
+    let decks = match DeckRegistry::load(config_dir).and_then(|r| r.list_decks()) {
+        Ok(decks) => decks,
+        Err(e) => {
+            return vec![Check::fail(
+                format!("Deck registry (vaults.toml) is unreadable: {:#}", e),
+                "Fix or restore vaults.toml in the config directory",
+            )]
+        }
+    };
+
+    if decks.is_empty() {
+        return vec![Check::warn(
+            "No decks registered",
+            "Run 'hc init' or 'hc deck create <name>'",
+        )];
+    }
+
+    let mut checks = Vec::new();
+    for deck in &decks {
+        if deck.path.exists() {
+            checks.push(Check::pass(format!("Deck '{}' file present", deck.name)));
+        } else {
+            checks.push(Check::warn(
+                format!(
+                    "Deck '{}' has no file yet at {}",
+                    deck.name,
+                    deck.path.display()
+                ),
+                "The file is created on first save; if it was moved, restore it to this path",
+            ));
+        }
+    }
+
+    let registered: Vec<&PathBuf> = decks.iter().map(|d| &d.path).collect();
+    let mut dirs = vec![config_dir.to_path_buf()];
+    let decks_dir = Config::decks_dir_for(config_dir);
+    if decks_dir != config_dir {
+        dirs.push(decks_dir);
+    }
+    let orphans = dirs
+        .iter()
+        .flat_map(|dir| unregistered_deck_files(dir, &registered));
+    for orphan in orphans {
+        checks.push(Check::warn(
+            format!("Deck file without a registry entry: {}", orphan.display()),
+            "Register it again by adding it to vaults.toml, or delete it if it is no longer needed",
+        ));
+    }
+
+    checks
+}
+
+fn unregistered_deck_files(config_dir: &Path, registered: &[&PathBuf]) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(config_dir) else {
+        return Vec::new();
+    };
+
+    let mut orphans: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "enc"))
+        .filter(|path| path.file_name().is_some_and(|name| name != "providers.enc"))
+        .filter(|path| !registered.contains(&path))
+        .collect();
+    orphans.sort();
+    orphans
+}
+
+fn check_ssh_agent() -> Check {
+    match SshAgent::connect() {
+        Ok(_) => Check::pass("ssh-agent is reachable"),
+        Err(e) => Check::warn(
+            format!("ssh-agent unavailable: {}", e),
+            "Needed only for 'hc ssh'; start one with 'eval $(ssh-agent -s)'",
+        ),
+    }
+}
+
+fn check_sshpass() -> Check {
+    if is_sshpass_available() {
+        Check::pass("sshpass is installed")
+    } else {
+        Check::warn(
+            "sshpass is not installed",
+            "Needed only for password-based 'hc ssh connect'; install it with your package manager",
+        )
+    }
+}
@@ -1,18 +1,29 @@
 use anyhow::{Context, Result};
-use std::io::Read;
-use std::path::Path;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use tempfile::NamedTempFile;
 
+use crate::cli::output::{print_payload, status};
+use crate::deck_context::skip_write_for_dry_run;
 use crate::domain::SecretResolver;
 use crate::infrastructure::KeyringManager;
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_inject(
     template: Option<String>,
     input: Option<String>,
     output: Option<String>,
+    in_place: bool,
+    backup: bool,
+    no_newline: bool,
     deck_name: Option<&str>,
     keyring: &KeyringManager,
     config_dir: &Path,
 ) -> Result<()> {
+    if in_place && matches!(input.as_deref(), Some("-") | None) {
+        anyhow::bail!("--in-place requires --input <file> (stdin cannot be rewritten)");
+    }
+
     let template_str = match (input.as_deref(), template.as_deref()) {
         (Some("-"), None) => {
             let mut buffer = String::new();
@@ -34,13 +45,50 @@ pub fn handle_inject(
 
     let rendered = SecretResolver::resolve_template(&template_str, deck_name, keyring, config_dir)?;
 
-    if let Some(output_path) = output {
+    if in_place {
+        let path = Path::new(input.as_deref().unwrap_or_default());
+        if skip_write_for_dry_run(path) {
+            return Ok(());
+        }
+        write_in_place(path, &rendered, backup)?;
+        status!("✓ Rendered template written back to {}", path.display());
+    } else if let Some(output_path) = output {
+        if skip_write_for_dry_run(Path::new(&output_path)) {
+            return Ok(());
+        }
         std::fs::write(&output_path, rendered.as_bytes())
             .with_context(|| format!("Failed to write to {}", output_path))?;
-        println!("✓ Rendered template written to {}", output_path);
+        status!("✓ Rendered template written to {}", output_path);
     } else {
-        println!("{}", rendered);
+        print_payload(&rendered, !no_newline)?;
     }
 
     Ok(())
 }
+
+/// Atomically replace `path` with `contents`, keeping its permissions.
+fn write_in_place(path: &Path, contents: &str, backup: bool) -> Result<()> {
+    let permissions = std::fs::metadata(path)
+        .with_context(|| format!("Failed to read metadata for {}", path.display()))?
+        .permissions();
+
+    if backup {
+        let backup_path = PathBuf::from(format!("{}.bak", path.display()));
+        std::fs::copy(path, &backup_path)
+            .with_context(|| format!("Failed to create backup {}", backup_path.display()))?;
+    }
+
+    let dir = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let mut temp = NamedTempFile::new_in(dir).context("Failed to create temporary file")?;
+    temp.write_all(contents.as_bytes())
+        .context("Failed to write rendered template")?;
+    std::fs::set_permissions(temp.path(), permissions)
+        .context("Failed to preserve file permissions")?;
+    temp.persist(path)
+        .with_context(|| format!("Failed to replace {}", path.display()))?;
+
+    Ok(())
+}
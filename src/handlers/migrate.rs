@@ -0,0 +1,38 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::cli::output::status;
+use crate::infrastructure::{DeckRegistry, KeyringManager, LegacyMigration};
+use crate::multi_deck_context::MultiDeckContext;
+
+pub fn handle_migrate(keyring: &KeyringManager, config_dir: &Path) -> Result<()> {
+    let registry = DeckRegistry::load(config_dir)?;
+
+    let name = match registry.migrate_legacy_deck()? {
+        LegacyMigration::NoLegacyDeck => {
+            status!(
+                "✓ Nothing to migrate: no legacy vault.enc in {}",
+                config_dir.display()
+            );
+            return Ok(());
+        }
+        LegacyMigration::AlreadyRegistered(name) => {
+            status!("✓ Legacy vault.enc is registered as deck '{}'", name);
+            name
+        }
+        LegacyMigration::Migrated(name) => {
+            status!("✓ Registered legacy vault.enc as deck '{}'", name);
+            name
+        }
+    };
+
+    let ctx = MultiDeckContext::load(Some(&name), keyring, config_dir)
+        .with_context(|| format!("Migrated deck '{}' could not be unlocked", name))?;
+    status!(
+        "✓ Deck '{}' decrypts correctly ({} hand(s))",
+        name,
+        ctx.inner.deck.list_hands().len()
+    );
+
+    Ok(())
+}
@@ -3,7 +3,9 @@ pub mod config;
 pub mod deal;
 pub mod deck;
 pub mod deck_management;
+pub mod doctor;
 pub mod inject;
+pub mod migrate;
 pub mod password;
 pub mod provider;
 pub mod read;
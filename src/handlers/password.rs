@@ -1,9 +1,13 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use copypasta::{ClipboardContext, ClipboardProvider};
+use sha2::{Digest, Sha256};
+use std::io::Write;
+use std::process::{Command, Stdio};
 use std::thread;
 use std::time::Duration;
 
-use crate::domain::PasswordService;
+use crate::cli::output::status;
+use crate::domain::{PasswordService, PasswordStrength};
 
 #[allow(clippy::too_many_arguments)]
 pub fn handle_generate(
@@ -15,39 +19,170 @@ pub fn handle_generate(
     no_digits: bool,
     no_symbols: bool,
     clip: bool,
+    no_clear: bool,
+    count: u32,
+    check: bool,
 ) -> Result<()> {
-    let password = PasswordService::generate_from_cli(
-        memorable,
-        words,
-        length,
-        no_uppercase,
-        no_lowercase,
-        no_digits,
-        no_symbols,
-    )?;
+    let passwords = (0..count)
+        .map(|_| {
+            PasswordService::generate_from_cli(
+                memorable,
+                words,
+                length,
+                no_uppercase,
+                no_lowercase,
+                no_digits,
+                no_symbols,
+            )
+        })
+        .collect::<Result<Vec<String>>>()?;
+
+    let mut remaining = passwords.iter();
 
     if clip {
-        copy_to_clipboard_with_clear(&password)?;
-        println!("Password copied to clipboard (will clear in 30 seconds)");
-    } else {
+        if let Some(first) = remaining.next() {
+            if no_clear {
+                copy_to_clipboard(first)?;
+            } else {
+                copy_to_clipboard_with_clear(first)?;
+            }
+            status!("Password copied to clipboard ({})", clear_notice(!no_clear));
+            if check {
+                status!("  {}", describe_strength(&PasswordService::score(first)));
+            }
+        }
+    }
+
+    for password in remaining {
         println!("{}", password);
+        if check {
+            status!("  {}", describe_strength(&PasswordService::score(password)));
+        }
     }
 
     Ok(())
 }
 
-pub fn copy_to_clipboard_with_clear(value: &str) -> Result<()> {
+/// e.g. "strong (3/4), ~2 years to crack offline"
+pub fn describe_strength(strength: &PasswordStrength) -> String {
+    format!(
+        "{} ({}/4), ~{} to crack offline",
+        strength.label(),
+        strength.score,
+        strength.crack_time
+    )
+}
+
+/// Warn, without blocking, about weak values typed into password-like cards
+pub fn warn_weak_passwords<'a>(cards: impl IntoIterator<Item = (&'a String, &'a String)>) {
+    for (key, value) in cards {
+        if !key.to_ascii_lowercase().contains("pass") {
+            continue;
+        }
+        let strength = PasswordService::score(value);
+        if strength.is_weak() {
+            eprintln!("⚠ Card '{}' is {}", key, describe_strength(&strength));
+            if let Some(warning) = &strength.warning {
+                eprintln!("  {}", warning);
+            }
+        }
+    }
+}
+
+pub const CLIPBOARD_CLEAR_SECONDS: u64 = 30;
+
+/// Describe what happens to copied data, for "Copied ..." messages
+pub fn clear_notice(clear: bool) -> String {
+    if clear {
+        format!("will clear in {} seconds", CLIPBOARD_CLEAR_SECONDS)
+    } else {
+        "not cleared automatically".to_string()
+    }
+}
+
+pub fn copy_to_clipboard(value: &str) -> Result<()> {
     let mut ctx = ClipboardContext::new()
         .map_err(|e| anyhow::anyhow!("Failed to initialize clipboard: {:?}", e))?;
     ctx.set_contents(value.to_string())
-        .map_err(|e| anyhow::anyhow!("Failed to copy to clipboard: {:?}", e))?;
+        .map_err(|e| anyhow::anyhow!("Failed to copy to clipboard: {:?}", e))
+}
 
-    thread::spawn(move || {
-        thread::sleep(Duration::from_secs(30));
-        if let Ok(mut ctx) = ClipboardContext::new() {
-            let _ = ctx.set_contents(String::new());
-        }
+/// Read the clipboard as text with trailing whitespace trimmed
+pub fn read_clipboard() -> Result<String> {
+    let mut ctx = ClipboardContext::new()
+        .map_err(|e| anyhow::anyhow!("Failed to initialize clipboard: {:?}", e))?;
+    let contents = ctx
+        .get_contents()
+        .map_err(|e| anyhow::anyhow!("Failed to read clipboard: {:?}", e))?;
+    let value = contents.trim_end();
+    if value.is_empty() {
+        anyhow::bail!("Clipboard is empty");
+    }
+    Ok(value.to_string())
+}
+
+/// Copy `value` and clear the clipboard after `CLIPBOARD_CLEAR_SECONDS`.
+///
+/// The clear runs in a detached `hc __clear-clipboard` process so it still
+/// happens after this command exits. Only a digest of the value is passed on,
+/// over the child's stdin so it never shows up in its argv, and the clipboard
+/// is left alone if it has changed in the meantime.
+pub fn copy_to_clipboard_with_clear(value: &str) -> Result<()> {
+    copy_to_clipboard(value)?;
+
+    let spawned = std::env::current_exe().and_then(|exe| {
+        let mut child = Command::new(exe)
+            .args([
+                "__clear-clipboard",
+                "--after",
+                &CLIPBOARD_CLEAR_SECONDS.to_string(),
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let mut stdin = child.stdin.take().expect("child stdin is piped");
+        writeln!(stdin, "{}", clipboard_digest(value))
     });
 
+    if spawned.is_err() {
+        // Best effort: clear from this process if it is still running by then.
+        thread::spawn(move || {
+            thread::sleep(Duration::from_secs(CLIPBOARD_CLEAR_SECONDS));
+            if let Ok(mut ctx) = ClipboardContext::new() {
+                let _ = ctx.set_contents(String::new());
+            }
+        });
+    }
+
+    Ok(())
+}
+
+/// Clear the clipboard after `after` seconds if it still holds the value whose
+/// digest arrives on stdin.
+pub fn handle_clear_clipboard(after: u64) -> Result<()> {
+    let mut digest = String::new();
+    std::io::stdin()
+        .read_line(&mut digest)
+        .context("Failed to read clipboard digest from stdin")?;
+    let digest = digest.trim();
+
+    thread::sleep(Duration::from_secs(after));
+
+    let mut ctx = ClipboardContext::new()
+        .map_err(|e| anyhow::anyhow!("Failed to initialize clipboard: {:?}", e))?;
+    let current = ctx.get_contents().unwrap_or_default();
+    if !digest.is_empty() && clipboard_digest(&current) == digest {
+        ctx.set_contents(String::new())
+            .map_err(|e| anyhow::anyhow!("Failed to clear clipboard: {:?}", e))?;
+    }
+
     Ok(())
 }
+
+fn clipboard_digest(value: &str) -> String {
+    Sha256::digest(value.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
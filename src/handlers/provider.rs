@@ -1,11 +1,12 @@
 use crate::cli::commands::{ProviderAddCommands, ProviderCommands, ProviderSecretsCommands};
+use crate::cli::input;
+use crate::cli::output::{mask_value, status};
 use crate::deck_context::DeckContext;
 use crate::domain::{
-    card_to_secret_name, error::ProviderError, Deck, ProviderConfig, TemplateEngine,
+    card_to_secret_name, error::ProviderError, Deck, ProviderConfig, SyncPlan, TemplateEngine,
 };
 use crate::infrastructure::{create_provider, CryptoServiceImpl, ProviderStorage};
 use anyhow::{Context, Result};
-use dialoguer::Confirm;
 use std::collections::HashMap;
 
 struct ExtractedCredentials {
@@ -64,32 +65,70 @@ fn extract_credentials(
                 credentials: creds,
             })
         }
+        ProviderAddCommands::Vercel {
+            provider_id,
+            project_id,
+            token,
+            target,
+        } => {
+            let mut creds = HashMap::new();
+            creds.insert(
+                "project_id".to_string(),
+                TemplateEngine::resolve_value(project_id, deck)?,
+            );
+            creds.insert(
+                "token".to_string(),
+                TemplateEngine::resolve_value(token, deck)?,
+            );
+            creds.insert("target".to_string(), target.clone());
+            Ok(ExtractedCredentials {
+                provider_type: "vercel",
+                provider_id: provider_id.clone(),
+                credentials: creds,
+            })
+        }
     }
 }
 
-pub fn handle_provider(ctx: &DeckContext, subcommand: &ProviderCommands) -> Result<()> {
+pub fn handle_provider(
+    ctx: &DeckContext,
+    subcommand: &ProviderCommands,
+    assume_yes: bool,
+) -> Result<()> {
     match subcommand {
         ProviderCommands::List => handle_list(ctx),
-        ProviderCommands::Add { provider } => handle_add(ctx, provider),
+        ProviderCommands::Add { verify, provider } => handle_add(ctx, provider, *verify),
         ProviderCommands::Edit {
             provider_type,
             provider_id,
+            verify,
             provider,
-        } => handle_edit(ctx, provider_type, provider_id, provider),
+        } => handle_edit(ctx, provider_type, provider_id, provider, *verify),
         ProviderCommands::Remove {
             provider_type,
             provider_id,
-        } => handle_remove(ctx, provider_type, provider_id),
-        ProviderCommands::Secrets { subcommand } => handle_secrets_command(ctx, subcommand),
+        } => handle_remove(ctx, provider_type, provider_id, assume_yes),
+        ProviderCommands::Test {
+            provider_type,
+            provider_id,
+        } => handle_test(ctx, provider_type, provider_id),
+        ProviderCommands::Secrets { subcommand } => {
+            handle_secrets_command(ctx, subcommand, assume_yes)
+        }
     }
 }
 
-fn handle_secrets_command(ctx: &DeckContext, subcommand: &ProviderSecretsCommands) -> Result<()> {
+fn handle_secrets_command(
+    ctx: &DeckContext,
+    subcommand: &ProviderSecretsCommands,
+    assume_yes: bool,
+) -> Result<()> {
     match subcommand {
         ProviderSecretsCommands::List {
             provider_type,
             provider_id,
-        } => handle_secrets_list(ctx, provider_type, provider_id),
+            json,
+        } => handle_secrets_list(ctx, provider_type, provider_id, *json),
         ProviderSecretsCommands::Add {
             provider_type,
             provider_id,
@@ -103,12 +142,19 @@ fn handle_secrets_command(ctx: &DeckContext, subcommand: &ProviderSecretsCommand
             card_field,
             as_name,
             *expand,
+            assume_yes,
         ),
         ProviderSecretsCommands::Remove {
             provider_type,
             provider_id,
             secret_name,
-        } => handle_secrets_remove(ctx, provider_type, provider_id, secret_name),
+        } => handle_secrets_remove(ctx, provider_type, provider_id, secret_name, assume_yes),
+        ProviderSecretsCommands::Sync {
+            provider_type,
+            provider_id,
+            hand,
+            prune,
+        } => handle_secrets_sync(ctx, provider_type, provider_id, hand, *prune, assume_yes),
     }
 }
 
@@ -150,12 +196,12 @@ fn get_provider_config<'a>(
         .ok_or_else(|| ProviderError::ProviderNotFound(key).into())
 }
 
-fn confirm_action(prompt: &str) -> Result<bool> {
-    Confirm::new()
-        .with_prompt(prompt)
-        .default(false)
-        .interact()
-        .context("Failed to read confirmation")
+fn confirm_action(prompt: &str, assume_yes: bool) -> Result<bool> {
+    if assume_yes {
+        return Ok(true);
+    }
+
+    input::prompt_confirm(prompt)
 }
 
 fn handle_edit(
@@ -163,6 +209,7 @@ fn handle_edit(
     provider_type: &str,
     provider_id: &str,
     provider: &ProviderAddCommands,
+    verify: bool,
 ) -> Result<()> {
     let mut configs = load_providers(ctx)?;
     let key = make_provider_key(provider_type, provider_id);
@@ -185,15 +232,18 @@ fn handle_edit(
         provider_id: provider_id.to_string(),
         credentials: extracted.credentials,
     };
+    if verify {
+        verify_credentials(&config)?;
+    }
 
     configs.insert(key.clone(), config);
     save_providers(ctx, &configs)?;
 
-    println!("✓ Provider updated: {} / {}", provider_type, provider_id);
+    status!("✓ Provider updated: {} / {}", provider_type, provider_id);
     Ok(())
 }
 
-fn handle_add(ctx: &DeckContext, provider: &ProviderAddCommands) -> Result<()> {
+fn handle_add(ctx: &DeckContext, provider: &ProviderAddCommands, verify: bool) -> Result<()> {
     let extracted = extract_credentials(provider, &ctx.deck)?;
     let mut configs = load_providers(ctx)?;
     let key = make_provider_key(extracted.provider_type, &extracted.provider_id);
@@ -211,13 +261,17 @@ fn handle_add(ctx: &DeckContext, provider: &ProviderAddCommands) -> Result<()> {
         provider_id: extracted.provider_id.clone(),
         credentials: extracted.credentials,
     };
+    if verify {
+        verify_credentials(&config)?;
+    }
 
     configs.insert(key, config);
     save_providers(ctx, &configs)?;
 
-    println!(
+    status!(
         "✓ Provider added: {} / {}",
-        extracted.provider_type, extracted.provider_id
+        extracted.provider_type,
+        extracted.provider_id
     );
     Ok(())
 }
@@ -229,6 +283,7 @@ fn handle_secrets_add(
     card_field: &str,
     as_name: &Option<String>,
     expand: bool,
+    assume_yes: bool,
 ) -> Result<()> {
     let configs = load_providers(ctx)?;
     let config = get_provider_config(&configs, provider_type, provider_id)?;
@@ -262,12 +317,16 @@ fn handle_secrets_add(
             provider_type,
             provider_id
         );
+        for card_key in hand.cards.keys() {
+            provider.validate_secret_name(&card_to_secret_name(card_key))?;
+        }
+
         for (card_key, value) in &hand.cards {
             let secret_name = card_to_secret_name(card_key);
-            println!("   {} = {} (masked)", secret_name, mask_value(value));
+            println!("   {} = {} (masked)", secret_name, mask_value(value, 4));
         }
 
-        if !confirm_action("Continue?")? {
+        if !confirm_action("Continue?", assume_yes)? {
             println!("Cancelled.");
             return Ok(());
         }
@@ -295,14 +354,16 @@ fn handle_secrets_add(
             .map(|s| s.to_string())
             .unwrap_or_else(|| card_to_secret_name(card));
 
+        provider.validate_secret_name(&secret_name)?;
+
         println!(
             "About to push secret to {} / {}:",
             provider_type, provider_id
         );
         println!("   Secret name: {}", secret_name);
-        println!("   Value: {} (masked)", mask_value(value));
+        println!("   Value: {} (masked)", mask_value(value, 4));
 
-        if !confirm_action("Continue?")? {
+        if !confirm_action("Continue?", assume_yes)? {
             println!("Cancelled.");
             return Ok(());
         }
@@ -316,6 +377,97 @@ fn handle_secrets_add(
     Ok(())
 }
 
+fn handle_secrets_sync(
+    ctx: &DeckContext,
+    provider_type: &str,
+    provider_id: &str,
+    hand_name: &str,
+    prune: bool,
+    assume_yes: bool,
+) -> Result<()> {
+    let configs = load_providers(ctx)?;
+    let config = get_provider_config(&configs, provider_type, provider_id)?;
+    let provider = create_provider(config)?;
+
+    let hand = ctx
+        .deck
+        .get_hand(hand_name)
+        .map_err(|_| ProviderError::CardNotFound(hand_name.to_string()))?;
+
+    let mut values = HashMap::new();
+    for (card_key, value) in &hand.cards {
+        let secret_name = card_to_secret_name(card_key);
+        provider.validate_secret_name(&secret_name)?;
+        if values.insert(secret_name.clone(), value).is_some() {
+            return Err(ProviderError::ConfigError(format!(
+                "Several cards in '{}' map to the secret name {}",
+                hand_name, secret_name
+            ))
+            .into());
+        }
+    }
+
+    let local: Vec<String> = values.keys().cloned().collect();
+    let remote = provider
+        .list_secrets()
+        .context("Failed to list current provider secrets")?;
+    let plan = SyncPlan::new(&local, &remote);
+
+    println!(
+        "Sync plan for {} / {} from hand '{}':",
+        provider_type, provider_id, hand_name
+    );
+    for name in &plan.create {
+        println!("   + {}", name);
+    }
+    for name in &plan.update {
+        println!("   ~ {}", name);
+    }
+    for name in &plan.delete {
+        if prune {
+            println!("   - {}", name);
+        } else {
+            println!("     {} (not in hand; kept, use --prune to delete)", name);
+        }
+    }
+
+    let deletions = if prune { plan.delete.len() } else { 0 };
+    if plan.create.is_empty() && plan.update.is_empty() && deletions == 0 {
+        println!("Nothing to sync.");
+        return Ok(());
+    }
+
+    if !confirm_action("Apply these changes?", assume_yes)? {
+        println!("Cancelled.");
+        return Ok(());
+    }
+
+    for name in plan.create.iter().chain(&plan.update) {
+        provider
+            .push_secret(name, values[name])
+            .with_context(|| format!("Failed to push secret: {}", name))?;
+        println!("Pushed: {}", name);
+    }
+    if prune {
+        for name in &plan.delete {
+            provider
+                .delete_secret(name)
+                .with_context(|| format!("Failed to delete secret: {}", name))?;
+            println!("Deleted: {}", name);
+        }
+    }
+
+    status!(
+        "✓ Synced {} / {}: {} created, {} updated, {} deleted",
+        provider_type,
+        provider_id,
+        plan.create.len(),
+        plan.update.len(),
+        deletions
+    );
+    Ok(())
+}
+
 fn handle_list(ctx: &DeckContext) -> Result<()> {
     let configs = load_providers(ctx)?;
 
@@ -335,11 +487,56 @@ fn handle_list(ctx: &DeckContext) -> Result<()> {
     Ok(())
 }
 
-fn handle_secrets_list(ctx: &DeckContext, provider_type: &str, provider_id: &str) -> Result<()> {
+fn handle_test(ctx: &DeckContext, provider_type: &str, provider_id: &str) -> Result<()> {
+    let configs = load_providers(ctx)?;
+    let config = get_provider_config(&configs, provider_type, provider_id)?;
+    verify_credentials(config)
+}
+
+/// Read-only connectivity check shared by `provider test` and `--verify`.
+fn verify_credentials(config: &ProviderConfig) -> Result<()> {
+    let provider = create_provider(config)?;
+
+    println!(
+        "Testing {} / {}...",
+        provider.provider_type(),
+        provider.provider_id()
+    );
+
+    let secrets = provider.list_secrets().with_context(|| {
+        format!(
+            "Credentials check failed for {} / {}",
+            provider.provider_type(),
+            provider.provider_id()
+        )
+    })?;
+
+    status!(
+        "✓ Credentials OK: {} / {} ({} secret(s) visible)",
+        provider.provider_type(),
+        provider.provider_id(),
+        secrets.len()
+    );
+    Ok(())
+}
+
+fn handle_secrets_list(
+    ctx: &DeckContext,
+    provider_type: &str,
+    provider_id: &str,
+    json: bool,
+) -> Result<()> {
     let configs = load_providers(ctx)?;
     let config = get_provider_config(&configs, provider_type, provider_id)?;
     let provider = create_provider(config)?;
-    let secrets = provider.list_secrets()?;
+    let mut secrets = provider.list_secrets()?;
+
+    if json {
+        secrets.sort();
+        let json = serde_json::to_string_pretty(&secrets).context("Failed to serialize secrets")?;
+        println!("{}", json);
+        return Ok(());
+    }
 
     if secrets.is_empty() {
         println!("No secrets found in {} / {}", provider_type, provider_id);
@@ -359,6 +556,7 @@ fn handle_secrets_remove(
     provider_type: &str,
     provider_id: &str,
     secret_name: &str,
+    assume_yes: bool,
 ) -> Result<()> {
     let configs = load_providers(ctx)?;
     let config = get_provider_config(&configs, provider_type, provider_id)?;
@@ -368,7 +566,7 @@ fn handle_secrets_remove(
         "Delete secret '{}' from {} / {}?",
         secret_name, provider_type, provider_id
     );
-    if !confirm_action(&prompt)? {
+    if !confirm_action(&prompt, assume_yes)? {
         println!("Cancelled.");
         return Ok(());
     }
@@ -377,11 +575,16 @@ fn handle_secrets_remove(
         .delete_secret(secret_name)
         .with_context(|| format!("Failed to delete secret: {}", secret_name))?;
 
-    println!("✓ Deleted secret: {}", secret_name);
+    status!("✓ Deleted secret: {}", secret_name);
     Ok(())
 }
 
-fn handle_remove(ctx: &DeckContext, provider_type: &str, provider_id: &str) -> Result<()> {
+fn handle_remove(
+    ctx: &DeckContext,
+    provider_type: &str,
+    provider_id: &str,
+    assume_yes: bool,
+) -> Result<()> {
     let mut configs = load_providers(ctx)?;
     let key = make_provider_key(provider_type, provider_id);
 
@@ -390,7 +593,7 @@ fn handle_remove(ctx: &DeckContext, provider_type: &str, provider_id: &str) -> R
     }
 
     let prompt = format!("Remove provider {} / {}?", provider_type, provider_id);
-    if !confirm_action(&prompt)? {
+    if !confirm_action(&prompt, assume_yes)? {
         println!("Cancelled.");
         return Ok(());
     }
@@ -398,14 +601,6 @@ fn handle_remove(ctx: &DeckContext, provider_type: &str, provider_id: &str) -> R
     configs.remove(&key);
     save_providers(ctx, &configs)?;
 
-    println!("✓ Provider removed: {} / {}", provider_type, provider_id);
+    status!("✓ Provider removed: {} / {}", provider_type, provider_id);
     Ok(())
 }
-
-fn mask_value(value: &str) -> String {
-    if value.len() <= 4 {
-        "****".to_string()
-    } else {
-        format!("{}****", &value[..4])
-    }
-}
@@ -1,16 +1,31 @@
 use anyhow::Result;
 use std::path::Path;
 
+use crate::cli::output::{print_payload, status};
+use crate::config::expand_tilde;
+use crate::deck_context::skip_write_for_dry_run;
 use crate::domain::SecretResolver;
-use crate::infrastructure::KeyringManager;
+use crate::infrastructure::{write_private_file, KeyringManager};
 
 pub fn handle_read(
     uri: &str,
+    out: Option<&str>,
+    no_newline: bool,
     deck_name: Option<&str>,
     keyring: &KeyringManager,
     config_dir: &Path,
 ) -> Result<()> {
     let value = SecretResolver::resolve(uri, deck_name, keyring, config_dir)?;
-    println!("{}", value);
+    match out {
+        Some(path) => {
+            let path = expand_tilde(path)?;
+            if skip_write_for_dry_run(&path) {
+                return Ok(());
+            }
+            write_private_file(&path, &value)?;
+            status!("✓ Wrote {} to {}", uri, path.display());
+        }
+        None => print_payload(&value, !no_newline)?,
+    }
     Ok(())
 }
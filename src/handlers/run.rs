@@ -2,11 +2,13 @@ use anyhow::{Context, Result};
 use std::path::Path;
 use std::process::Command;
 
-use crate::domain::SecretResolver;
+use crate::domain::{parse_env_file, sanitize_env_key, SecretResolver};
 use crate::infrastructure::KeyringManager;
 
 pub fn handle_run(
     env_vars: Vec<(String, String)>,
+    env_file: Option<&str>,
+    namespace: bool,
     command: &[String],
     deck_name: Option<&str>,
     keyring: &KeyringManager,
@@ -19,11 +21,22 @@ pub fn handle_run(
     let mut cmd = Command::new(&command[0]);
     cmd.args(&command[1..]);
 
-    for (key, value) in env_vars {
-        let resolved_value = if SecretResolver::has_uri_references(&value) {
-            SecretResolver::resolve_template(&value, deck_name, keyring, config_dir)?
+    // Later `env` calls win, so apply the env file before explicit --env flags.
+    let file_vars = match env_file {
+        Some(path) => read_env_file(path)?,
+        None => Vec::new(),
+    };
+
+    for (key, value) in file_vars.into_iter().chain(env_vars) {
+        let (resolved_value, hands) = if SecretResolver::has_uri_references(&value) {
+            SecretResolver::resolve_template_with_hands(&value, deck_name, keyring, config_dir)?
+        } else {
+            (value, Vec::new())
+        };
+        let key = if namespace {
+            namespaced_key(key, &hands)?
         } else {
-            value
+            key
         };
 
         cmd.env(key, resolved_value);
@@ -37,3 +50,24 @@ pub fn handle_run(
 
     Ok(())
 }
+
+/// Prefix `key` with the name of the hand its value resolved from, e.g.
+/// `PASSWORD=hc://prod/myapp/password` becomes `MYAPP_PASSWORD`. Literal
+/// values keep their key.
+fn namespaced_key(key: String, hands: &[String]) -> Result<String> {
+    match hands {
+        [] => Ok(key),
+        [hand] => Ok(format!("{}_{}", sanitize_env_key(hand).to_uppercase(), key)),
+        _ => anyhow::bail!(
+            "Cannot namespace {}: its value references several hands ({})",
+            key,
+            hands.join(", ")
+        ),
+    }
+}
+
+pub fn read_env_file(path: &str) -> Result<Vec<(String, String)>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read env file {}", path))?;
+    parse_env_file(&content).with_context(|| format!("Invalid env file {}", path))
+}
@@ -1,14 +1,17 @@
 use anyhow::Result;
 use std::path::Path;
+use std::thread;
+use std::time::Duration;
 
+use crate::cli::output::status;
 use crate::config::Config;
-use crate::infrastructure::{DeckRegistry, SessionManager};
+use crate::infrastructure::{find_local_deck, DeckRegistry, SessionManager};
 
 pub fn handle_lock(config_dir: &Path) -> Result<()> {
-    let config = Config::load(config_dir)?;
     let registry = DeckRegistry::load(config_dir)?;
 
-    let active_deck = registry.get_active_deck()?;
+    let active_deck = registry.resolve_deck(None)?;
+    let config = Config::load_for_deck(config_dir, &active_deck.name)?;
     let session = SessionManager::new(
         config_dir,
         &active_deck.name,
@@ -16,16 +19,45 @@ pub fn handle_lock(config_dir: &Path) -> Result<()> {
     );
 
     session.clear_session()?;
-    println!("✓ Deck '{}' locked. Session cleared.", active_deck.name);
+    status!("✓ Deck '{}' locked. Session cleared.", active_deck.name);
 
     Ok(())
 }
 
+/// Poll every deck's session and clear any that have been idle past
+/// `session_timeout_minutes`. Runs until interrupted; no key material is held,
+/// so Ctrl-C (SIGINT) simply ends the loop.
+pub fn handle_lock_idle(interval_secs: u64, config_dir: &Path) -> Result<()> {
+    status!(
+        "Watching sessions (checking every {}s, Ctrl-C to stop)...",
+        interval_secs
+    );
+
+    loop {
+        let config = Config::load(config_dir)?;
+        let registry = DeckRegistry::load(config_dir)?;
+
+        for deck in registry.list_decks()? {
+            let mut config = config.clone();
+            config.apply_overrides(&deck.overrides);
+            let session =
+                SessionManager::new(config_dir, &deck.name, config.session_timeout_minutes);
+            match session.expire_if_idle() {
+                Ok(true) => status!("✓ Deck '{}' locked after inactivity", deck.name),
+                Ok(false) => {}
+                Err(e) => eprintln!("⚠ Failed to check session for '{}': {}", deck.name, e),
+            }
+        }
+
+        thread::sleep(Duration::from_secs(interval_secs));
+    }
+}
+
 pub fn handle_status(config_dir: &Path) -> Result<()> {
-    let config = Config::load(config_dir)?;
     let registry = DeckRegistry::load(config_dir)?;
 
-    let active_deck = registry.get_active_deck()?;
+    let active_deck = registry.resolve_deck(None)?;
+    let config = Config::load_for_deck(config_dir, &active_deck.name)?;
     let session = SessionManager::new(
         config_dir,
         &active_deck.name,
@@ -47,3 +79,34 @@ pub fn handle_status(config_dir: &Path) -> Result<()> {
 
     Ok(())
 }
+
+pub fn handle_current(deck_name: Option<&str>, config_dir: &Path) -> Result<()> {
+    let registry = DeckRegistry::load(config_dir)?;
+
+    let deck = registry.resolve_deck(deck_name)?;
+    let config = Config::load_for_deck(config_dir, &deck.name)?;
+    let session = SessionManager::new(config_dir, &deck.name, config.session_timeout_minutes);
+
+    let local = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| find_local_deck(&cwd));
+    let source = match (deck_name, local) {
+        (Some(_), _) => "--deck".to_string(),
+        (None, Some((_, marker))) => format!("{}", marker.display()),
+        (None, None) => "active deck".to_string(),
+    };
+
+    println!("{}", deck.name);
+    status!("  Source: {}", source);
+    status!("  Path: {}", deck.path.display());
+    status!(
+        "  Session: {}",
+        if session.is_active() {
+            "active"
+        } else {
+            "locked"
+        }
+    );
+
+    Ok(())
+}
@@ -1,15 +1,23 @@
 use anyhow::{Context, Result};
 use std::collections::HashMap;
+use std::io::{IsTerminal, Write};
 use std::path::Path;
 use std::process::Command;
+use tempfile::NamedTempFile;
 
 use crate::cli::commands::SshCommands;
-use crate::domain::{find_hand_by_name_or_alias, validate_private_key, Hand};
-use crate::infrastructure::{KeyringManager, SshAgent};
+use crate::cli::input;
+use crate::cli::output::status;
+use crate::domain::{
+    find_hand_by_name_or_alias, glob_match, update_ssh_cards, validate_private_key, Deck, Hand,
+    SshAuthMethod,
+};
+use crate::infrastructure::{write_private_key_file, KeyringManager, SshAgent};
 use crate::multi_deck_context::MultiDeckContext;
 
 pub fn handle_ssh(
     subcommand: SshCommands,
+    assume_yes: bool,
     deck_name: Option<&str>,
     keyring: &KeyringManager,
     config_dir: &Path,
@@ -24,6 +32,9 @@ pub fn handle_ssh(
             private_key,
             public_key,
             passphrase,
+            proxy_jump,
+            host_key,
+            no_update,
         } => handle_ssh_add(
             &name,
             SshAddOptions {
@@ -34,6 +45,10 @@ pub fn handle_ssh(
                 private_key_path: private_key,
                 public_key_path: public_key,
                 passphrase,
+                proxy_jump,
+                host_key,
+                no_update,
+                assume_yes,
             },
             deck_name,
             keyring,
@@ -42,11 +57,57 @@ pub fn handle_ssh(
         SshCommands::Load { name, lifetime } => {
             handle_ssh_load(&name, lifetime, deck_name, keyring, config_dir)
         }
-        SshCommands::Unload { name } => handle_ssh_unload(&name, deck_name, keyring, config_dir),
+        SshCommands::Unload { name, all } => match name {
+            Some(name) if !all => handle_ssh_unload(&name, deck_name, keyring, config_dir),
+            _ => handle_ssh_unload_all(),
+        },
         SshCommands::List => handle_ssh_list(deck_name, keyring, config_dir),
-        SshCommands::Connect { target, ssh_args } => {
-            handle_ssh_connect(&target, ssh_args, deck_name, keyring, config_dir)
-        }
+        SshCommands::Connect {
+            target,
+            confirm,
+            ssh_args,
+        } => handle_ssh_connect(&target, confirm, ssh_args, deck_name, keyring, config_dir),
+        SshCommands::Scp {
+            target,
+            local,
+            remote,
+            download,
+            recursive,
+            scp_args,
+        } => handle_ssh_copy(
+            "scp",
+            &target,
+            SshCopyOptions {
+                local,
+                remote,
+                download,
+                recursive,
+                extra_args: scp_args,
+            },
+            deck_name,
+            keyring,
+            config_dir,
+        ),
+        SshCommands::Rsync {
+            target,
+            local,
+            remote,
+            download,
+            rsync_args,
+        } => handle_ssh_copy(
+            "rsync",
+            &target,
+            SshCopyOptions {
+                local,
+                remote,
+                download,
+                recursive: false,
+                extra_args: rsync_args,
+            },
+            deck_name,
+            keyring,
+            config_dir,
+        ),
     }
 }
 
@@ -58,6 +119,10 @@ struct SshAddOptions {
     private_key_path: Option<String>,
     public_key_path: Option<String>,
     passphrase: Option<String>,
+    proxy_jump: Option<String>,
+    host_key: Option<String>,
+    no_update: bool,
+    assume_yes: bool,
 }
 
 fn handle_ssh_add(
@@ -75,6 +140,10 @@ fn handle_ssh_add(
         private_key_path,
         public_key_path,
         passphrase,
+        proxy_jump,
+        host_key,
+        no_update,
+        assume_yes,
     } = options;
     // Validation: --alias is mutually exclusive with other options
     if alias.is_some()
@@ -97,19 +166,54 @@ fn handle_ssh_add(
         );
     }
 
+    let host_key = host_key.map(|line| normalize_host_key(&line)).transpose()?;
+
     let mut ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+
+    // Re-running `ssh add` updates the hand, keeping cards not given again.
+    let existing = match ctx.inner.deck.get_hand(name) {
+        Ok(_) if no_update => anyhow::bail!("Hand '{}' already exists", name),
+        Ok(hand) => {
+            if !assume_yes
+                && !input::prompt_confirm(&format!("Hand '{}' already exists. Update it?", name))?
+            {
+                println!("Cancelled.");
+                return Ok(());
+            }
+            Some(hand.cards.clone())
+        }
+        Err(_) => None,
+    };
+    let existing_card = |key: &str| existing.as_ref().and_then(|c| c.get(key).cloned());
+    let verb = if existing.is_some() {
+        "updated"
+    } else {
+        "created"
+    };
+
     let mut cards = HashMap::new();
+    let auth_method = if alias.is_some() {
+        Some(SshAuthMethod::Alias)
+    } else if password.is_some() {
+        Some(SshAuthMethod::Password)
+    } else if private_key_path.is_some() {
+        Some(SshAuthMethod::Key)
+    } else {
+        None
+    };
 
     if let Some(alias_value) = alias {
         // Pattern 1: Alias only (SSH config managed)
         cards.insert("alias".to_string(), alias_value);
-        println!("✓ SSH hand '{}' created with alias authentication", name);
+        status!("✓ SSH hand '{}' {} with alias authentication", name, verb);
     } else {
         // Pattern 2 & 3: Direct management (username + hostname required)
-        let username_value =
-            username.context("Option --username is required when not using --alias")?;
-        let hostname_value =
-            hostname.context("Option --hostname is required when not using --alias")?;
+        let username_value = username
+            .or_else(|| existing_card("username"))
+            .context("Option --username is required when not using --alias")?;
+        let hostname_value = hostname
+            .or_else(|| existing_card("hostname"))
+            .context("Option --hostname is required when not using --alias")?;
 
         cards.insert("username".to_string(), username_value.clone());
         cards.insert("hostname".to_string(), hostname_value.clone());
@@ -121,7 +225,11 @@ fn handle_ssh_add(
         if let Some(password_value) = password {
             // Pattern 2: Password authentication
             cards.insert("password".to_string(), password_value);
-            println!("✓ SSH hand '{}' created with password authentication", name);
+            status!(
+                "✓ SSH hand '{}' {} with password authentication",
+                name,
+                verb
+            );
         } else if let Some(private_key_path_value) = private_key_path {
             // Pattern 3: Key authentication
             let expanded_private_key_path = expand_tilde(&private_key_path_value)?;
@@ -152,17 +260,36 @@ fn handle_ssh_add(
                 cards.insert("passphrase".to_string(), passphrase_value);
             }
 
-            println!("✓ SSH hand '{}' created with key authentication", name);
-        } else {
+            status!("✓ SSH hand '{}' {} with key authentication", name, verb);
+        } else if existing.is_none() {
             anyhow::bail!("Either --password or --private-key is required when not using --alias");
+        } else if let Some(passphrase_value) = passphrase {
+            // Updating only the passphrase of an existing key
+            cards.insert("passphrase".to_string(), passphrase_value);
         }
     }
 
-    let hand = Hand::new(name.to_string(), cards, None);
-    ctx.inner.deck.add_hand(hand)?;
+    if let Some(proxy_jump_value) = proxy_jump {
+        cards.insert("proxy_jump".to_string(), proxy_jump_value);
+    }
+
+    if let Some(host_key_value) = host_key {
+        cards.insert("host_key".to_string(), host_key_value);
+    }
+
+    if existing.is_some() {
+        let hand = ctx.inner.deck.get_hand_mut(name)?;
+        let previous = hand.clone();
+        update_ssh_cards(&mut hand.cards, cards, auth_method);
+        hand.record_changes(&previous);
+        hand.touch();
+    } else {
+        let hand = Hand::new(name.to_string(), cards, None);
+        ctx.inner.deck.add_hand(hand)?;
+    }
     ctx.save()?;
 
-    println!("✓ Hand '{}' saved to deck", name);
+    status!("✓ Hand '{}' saved to deck", name);
 
     Ok(())
 }
@@ -202,10 +329,10 @@ fn handle_ssh_load(
     let agent = SshAgent::connect()?;
     agent.add_identity(private_key, passphrase, lifetime)?;
 
-    println!("✓ SSH key '{}' loaded into ssh-agent", hand_name);
+    status!("✓ SSH key '{}' loaded into ssh-agent", hand_name);
     match lifetime {
-        Some(0) => println!("  Lifetime: forever"),
-        Some(sec) => println!("  Lifetime: {} seconds", sec),
+        Some(0) => status!("  Lifetime: forever"),
+        Some(sec) => status!("  Lifetime: {} seconds", sec),
         None => {}
     }
 
@@ -232,7 +359,21 @@ fn handle_ssh_unload(
     let agent = SshAgent::connect()?;
     agent.remove_identity(&public_key)?;
 
-    println!("✓ SSH key removed from ssh-agent");
+    status!("✓ SSH key removed from ssh-agent");
+    Ok(())
+}
+
+fn handle_ssh_unload_all() -> Result<()> {
+    let agent = SshAgent::connect()?;
+    let loaded = agent.list_identities()?;
+
+    if loaded.is_empty() {
+        status!("No identities loaded in ssh-agent");
+        return Ok(());
+    }
+
+    agent.remove_all_identities()?;
+    status!("✓ Removed {} identities from ssh-agent", loaded.len());
     Ok(())
 }
 
@@ -257,13 +398,16 @@ fn handle_ssh_list(
             if let Some(t) = target {
                 println!("    → {}", t);
             }
+            if let Some(jump) = hand.cards.get("proxy_jump") {
+                println!("    via {}", jump);
+            }
         }
     }
 
     Ok(())
 }
 
-fn is_ssh_hand(hand: &Hand) -> bool {
+pub fn is_ssh_hand(hand: &Hand) -> bool {
     hand.cards.contains_key("alias")
         || hand.cards.contains_key("private_key")
         || (hand.cards.contains_key("username") && hand.cards.contains_key("hostname"))
@@ -291,89 +435,599 @@ fn get_ssh_target(hand: &Hand) -> Option<String> {
 
 fn handle_ssh_connect(
     target: &str,
+    confirm: bool,
     ssh_args: Vec<String>,
     deck_name: Option<&str>,
     keyring: &KeyringManager,
     config_dir: &Path,
 ) -> Result<()> {
-    let ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+    let mut ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+    let session = SshSession::prepare(&ctx.inner.deck, target)?;
 
-    let hand_name = find_hand_by_name_or_alias(&ctx.inner.deck, target)
-        .ok_or_else(|| anyhow::anyhow!("No hand found with name or alias '{}'", target))?;
+    if session.hand.cards.contains_key("alias") {
+        show_ssh_config_resolution(&session.ssh_target);
+    }
 
-    let hand = ctx.inner.deck.get_hand(&hand_name)?;
+    if confirm && !input::prompt_confirm(&format!("Connect to {}?", session.ssh_target))? {
+        println!("Cancelled.");
+        return Ok(());
+    }
 
-    let ssh_target = if target.contains('@') {
-        target.to_string()
-    } else {
-        // Get CSV list from host or alias card
-        let csv_value = hand
-            .cards
-            .get("host")
-            .or_else(|| hand.cards.get("alias"))
-            .context("Hand has no 'host' or 'alias' card and target is not in user@host format")?;
-
-        // Parse CSV and try to match the provided target exactly
-        let aliases: Vec<String> = csv_value.split(',').map(|s| s.trim().to_string()).collect();
-
-        // Try exact match first, otherwise use first entry
-        aliases
-            .iter()
-            .find(|alias| *alias == target)
-            .cloned()
-            .or_else(|| aliases.first().cloned())
-            .context("No valid alias found in CSV list")?
-    };
+    match &session.proxy_jump {
+        Some(jump) => status!("Connecting to {} via {}...", session.ssh_target, jump),
+        None => status!("Connecting to {}...", session.ssh_target),
+    }
 
-    let has_alias = hand.cards.contains_key("alias");
-    let has_private_key = hand.cards.contains_key("private_key");
-    let has_password = hand.cards.contains_key("password");
+    let config = &ctx.inner.config;
+    let lifetime = Some(config.ssh_key_lifetime_seconds).filter(|sec| *sec > 0);
+    let unload_on_exit = config.ssh_unload_on_exit;
+    let key_auth = session.authenticate(lifetime)?;
 
-    if !has_alias && !has_private_key && !has_password {
-        anyhow::bail!(
-            "Hand '{}' must have either 'alias', 'private_key', or 'password' card for SSH authentication",
-            hand_name
-        );
+    let options = session.ssh_options()?;
+    let mut ssh_command_args = options.args.clone();
+    ssh_command_args.extend(key_auth.ssh_args());
+    ssh_command_args.push(session.ssh_target.clone());
+    ssh_command_args.extend(ssh_args);
+    let status = session.run("ssh", &ssh_command_args)?;
+
+    if matches!(key_auth, KeyAuth::Agent) && unload_on_exit {
+        match session.hand.cards.get("public_key") {
+            Some(public_key) => {
+                SshAgent::connect()?.remove_public_key(public_key)?;
+                status!("✓ SSH key '{}' removed from ssh-agent", session.hand_name);
+            }
+            None => eprintln!(
+                "⚠ Hand '{}' has no 'public_key' card; key left in ssh-agent",
+                session.hand_name
+            ),
+        }
     }
 
-    println!("Connecting to {}...", ssh_target);
+    if !status.success() {
+        anyhow::bail!("SSH connection failed");
+    }
 
-    let status = if let Some(password) = hand.cards.get("password") {
-        execute_ssh_with_password(&ssh_target, &ssh_args, password)?
-    } else if let Some(private_key) = hand.cards.get("private_key") {
-        validate_private_key(private_key)?;
+    if session.hand.cards.contains_key("host_key") {
+        return Ok(());
+    }
 
-        let passphrase = hand.cards.get("passphrase").map(|s| s.as_str());
+    let hand_name = session.hand_name.clone();
+    let captured = capture_host_key(&session.ssh_target);
+    if let Some(host_key) = captured {
+        offer_host_key_pin(&mut ctx, &hand_name, host_key)?;
+    }
 
-        let agent = SshAgent::connect()?;
-        agent.add_identity(private_key, passphrase, None)?;
+    Ok(())
+}
 
-        println!("✓ SSH key '{}' loaded into ssh-agent", hand_name);
+/// Offer to store the host key accepted during the first connection
+fn offer_host_key_pin(ctx: &mut MultiDeckContext, hand_name: &str, host_key: String) -> Result<()> {
+    if !std::io::stdin().is_terminal() {
+        return Ok(());
+    }
+
+    println!("\nHost key presented by the server:");
+    for line in host_key.lines() {
+        println!("  {}", line);
+    }
+    if !input::prompt_confirm(&format!("Pin this host key to hand '{}'?", hand_name))? {
+        return Ok(());
+    }
+
+    let hand = ctx.inner.deck.get_hand_mut(hand_name)?;
+    hand.cards.insert("host_key".to_string(), host_key);
+    hand.touch();
+    ctx.save()?;
+
+    status!("✓ Host key pinned to hand '{}'", hand_name);
+    Ok(())
+}
 
-        Command::new("ssh")
-            .arg(&ssh_target)
-            .args(&ssh_args)
-            .status()
-            .context("Failed to execute ssh command")?
+fn handle_ssh_copy(
+    program: &str,
+    target: &str,
+    options: SshCopyOptions,
+    deck_name: Option<&str>,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    let ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
+    let session = SshSession::prepare(&ctx.inner.deck, target)?;
+
+    let remote = format!("{}:{}", session.ssh_target, options.remote);
+    let (source, destination) = if options.download {
+        (remote, options.local)
     } else {
-        // Alias mode: use ssh_target directly (managed by ~/.ssh/config)
-        Command::new("ssh")
-            .arg(&ssh_target)
-            .args(&ssh_args)
-            .status()
-            .context("Failed to execute ssh command")?
+        (options.local, remote)
     };
 
+    let lifetime = Some(ctx.inner.config.ssh_key_lifetime_seconds).filter(|sec| *sec > 0);
+    let key_auth = session.authenticate(lifetime)?;
+
+    let ssh_options = session.ssh_options()?;
+    let mut option_args = ssh_options.args.clone();
+    option_args.extend(key_auth.ssh_args());
+    let mut args = Vec::new();
+    if program == "rsync" {
+        if !option_args.is_empty() {
+            args.push("-e".to_string());
+            args.push(format!("ssh {}", option_args.join(" ")));
+        }
+    } else {
+        args.extend(option_args);
+        if options.recursive {
+            args.push("-r".to_string());
+        }
+    }
+    args.extend(options.extra_args);
+    args.push(source.clone());
+    args.push(destination.clone());
+
+    status!("Copying {} → {}...", source, destination);
+    let status = session.run(program, &args)?;
+
     if !status.success() {
-        anyhow::bail!("SSH connection failed");
+        anyhow::bail!("{} failed", program);
     }
 
+    status!("✓ Copy complete");
     Ok(())
 }
 
-fn execute_ssh_with_password(
-    ssh_target: &str,
-    ssh_args: &[String],
+struct SshCopyOptions {
+    local: String,
+    remote: String,
+    download: bool,
+    recursive: bool,
+    extra_args: Vec<String>,
+}
+
+const PINNED_HOST_KEY_ALIAS: &str = "hc-pinned-host";
+
+/// ssh command-line options; keeps the pinned known_hosts file alive while in use
+struct SshOptions {
+    args: Vec<String>,
+    _known_hosts: Option<NamedTempFile>,
+}
+
+/// How a key hand's private key reaches ssh for one connection
+enum KeyAuth {
+    /// Password or alias hand; no key to hand over
+    NotNeeded,
+    /// Loaded into ssh-agent
+    Agent,
+    /// No usable agent: passed with `-i`; the file is deleted on drop
+    IdentityFile(NamedTempFile),
+}
+
+impl KeyAuth {
+    fn ssh_args(&self) -> Vec<String> {
+        match self {
+            KeyAuth::IdentityFile(file) => vec![
+                "-i".to_string(),
+                file.path().display().to_string(),
+                "-o".to_string(),
+                "IdentitiesOnly=yes".to_string(),
+            ],
+            KeyAuth::NotNeeded | KeyAuth::Agent => Vec::new(),
+        }
+    }
+}
+
+/// An SSH hand resolved for a connection target, ready to authenticate
+struct SshSession<'a> {
+    hand_name: String,
+    hand: &'a Hand,
+    ssh_target: String,
+    proxy_jump: Option<String>,
+    /// Jump hands whose private key must be in ssh-agent for `ssh -J`
+    jump_hands: Vec<(String, &'a Hand)>,
+}
+
+impl<'a> SshSession<'a> {
+    fn prepare(deck: &'a Deck, target: &str) -> Result<Self> {
+        let hand_name = find_hand_by_name_or_alias(deck, target)
+            .ok_or_else(|| anyhow::anyhow!("No hand found with name or alias '{}'", target))?;
+
+        let hand = deck.get_hand(&hand_name)?;
+
+        let ssh_target = if target.contains('@') {
+            target.to_string()
+        } else {
+            // Get CSV list from host or alias card
+            let csv_value = hand
+                .cards
+                .get("host")
+                .or_else(|| hand.cards.get("alias"))
+                .context(
+                    "Hand has no 'host' or 'alias' card and target is not in user@host format",
+                )?;
+
+            // Parse CSV and try to match the provided target exactly
+            let aliases: Vec<String> = csv_value.split(',').map(|s| s.trim().to_string()).collect();
+
+            // Try exact match first, otherwise use first entry
+            aliases
+                .iter()
+                .find(|alias| *alias == target)
+                .cloned()
+                .or_else(|| aliases.first().cloned())
+                .context("No valid alias found in CSV list")?
+        };
+
+        let has_alias = hand.cards.contains_key("alias");
+        let has_private_key = hand.cards.contains_key("private_key");
+        let has_password = hand.cards.contains_key("password");
+
+        if !has_alias && !has_private_key && !has_password {
+            anyhow::bail!(
+                "Hand '{}' must have either 'alias', 'private_key', or 'password' card for SSH authentication",
+                hand_name
+            );
+        }
+
+        let (proxy_jump, jump_hands) = match hand.cards.get("proxy_jump") {
+            Some(value) => {
+                let (hops, jump_hands) = resolve_proxy_jump(deck, value)?;
+                (Some(hops), jump_hands)
+            }
+            None => (None, Vec::new()),
+        };
+
+        Ok(Self {
+            hand_name,
+            hand,
+            ssh_target,
+            proxy_jump,
+            jump_hands,
+        })
+    }
+
+    /// Build ssh options for ProxyJump and host key pinning.
+    ///
+    /// A pinned `host_key` is written to a temporary known_hosts file under a
+    /// fixed `HostKeyAlias` with strict checking, so the connection fails if
+    /// the server presents any other key.
+    fn ssh_options(&self) -> Result<SshOptions> {
+        let mut args = Vec::new();
+
+        if let Some(jump) = &self.proxy_jump {
+            args.push("-J".to_string());
+            args.push(jump.clone());
+        }
+
+        let known_hosts = match self.hand.cards.get("host_key") {
+            Some(host_key) => {
+                let mut file = NamedTempFile::new().context("Failed to create known_hosts file")?;
+                for line in host_key.lines().filter(|l| !l.trim().is_empty()) {
+                    writeln!(file, "{} {}", PINNED_HOST_KEY_ALIAS, line.trim())
+                        .context("Failed to write known_hosts file")?;
+                }
+                file.flush()?;
+
+                for option in [
+                    format!("HostKeyAlias={}", PINNED_HOST_KEY_ALIAS),
+                    format!("UserKnownHostsFile={}", file.path().display()),
+                    "GlobalKnownHostsFile=/dev/null".to_string(),
+                    "StrictHostKeyChecking=yes".to_string(),
+                ] {
+                    args.push("-o".to_string());
+                    args.push(option);
+                }
+                Some(file)
+            }
+            None => None,
+        };
+
+        Ok(SshOptions {
+            args,
+            _known_hosts: known_hosts,
+        })
+    }
+
+    /// Load jump hosts' keys into ssh-agent, then the hand's own private key
+    /// (key-based hands only), or write it to a temporary identity file when
+    /// no agent is reachable. Agent keys expire after `lifetime` seconds.
+    fn authenticate(&self, lifetime: Option<u32>) -> Result<KeyAuth> {
+        self.load_jump_keys(lifetime)?;
+
+        if self.hand.cards.contains_key("password") {
+            return Ok(KeyAuth::NotNeeded);
+        }
+
+        if let Some(private_key) = self.hand.cards.get("private_key") {
+            validate_private_key(private_key)?;
+
+            let agent = match SshAgent::connect() {
+                Ok(agent) => agent,
+                Err(_) => {
+                    eprintln!("⚠ ssh-agent is not available; passing the key to ssh with -i");
+                    if self.hand.cards.contains_key("passphrase") {
+                        eprintln!("  ssh will ask for the key's passphrase");
+                    }
+                    let identity_file = write_private_key_file(private_key)?;
+                    ignore_interrupts();
+                    return Ok(KeyAuth::IdentityFile(identity_file));
+                }
+            };
+
+            let passphrase = self.hand.cards.get("passphrase").map(|s| s.as_str());
+            agent.add_identity(private_key, passphrase, lifetime)?;
+
+            status!("✓ SSH key '{}' loaded into ssh-agent", self.hand_name);
+            if let Some(sec) = lifetime {
+                status!("  Lifetime: {} seconds", sec);
+            }
+            return Ok(KeyAuth::Agent);
+        }
+
+        Ok(KeyAuth::NotNeeded)
+    }
+
+    fn load_jump_keys(&self, lifetime: Option<u32>) -> Result<()> {
+        for (jump_hand_name, jump_hand) in &self.jump_hands {
+            let Some(private_key) = jump_hand.cards.get("private_key") else {
+                continue;
+            };
+            let passphrase = jump_hand.cards.get("passphrase").map(|s| s.as_str());
+            match SshAgent::connect() {
+                Ok(agent) => {
+                    agent.add_identity(private_key, passphrase, lifetime)?;
+                    status!("✓ SSH key '{}' loaded into ssh-agent", jump_hand_name);
+                }
+                // ssh -J can only take jump host keys from an agent or ~/.ssh
+                Err(e) => eprintln!("⚠ {}; key for jump hand '{}' not loaded", e, jump_hand_name),
+            }
+        }
+        Ok(())
+    }
+
+    /// Run an ssh-based program, wrapping it in sshpass for password hands
+    fn run(&self, program: &str, args: &[String]) -> Result<std::process::ExitStatus> {
+        match self.hand.cards.get("password") {
+            Some(password) => execute_with_password(program, args, password),
+            // Key hands use the agent; alias hands are managed by ~/.ssh/config
+            None => Command::new(program)
+                .args(args)
+                .status()
+                .with_context(|| format!("Failed to execute {} command", program)),
+        }
+    }
+}
+
+/// Keep Ctrl-C from killing hc while ssh runs, so a temporary identity file is
+/// still removed on the way out. ssh shares the terminal and gets the signal itself.
+fn ignore_interrupts() {
+    if let Err(e) = ctrlc::set_handler(|| {}) {
+        log::debug!("Could not install the Ctrl-C handler: {}", e);
+    }
+}
+
+/// Connection details resolved for a host alias from `~/.ssh/config`
+#[derive(Debug, Default, PartialEq)]
+struct SshConfigHost {
+    hostname: Option<String>,
+    user: Option<String>,
+    port: Option<String>,
+    identity_file: Option<String>,
+}
+
+fn show_ssh_config_resolution(alias: &str) {
+    let Ok(home) = std::env::var("HOME") else {
+        return;
+    };
+    let config_path = Path::new(&home).join(".ssh").join("config");
+    let Ok(content) = std::fs::read_to_string(&config_path) else {
+        return;
+    };
+
+    match resolve_ssh_config_host(&content, alias) {
+        Some(host) => {
+            let hostname = host.hostname.as_deref().unwrap_or(alias);
+            let target = match &host.user {
+                Some(user) => format!("{}@{}", user, hostname),
+                None => hostname.to_string(),
+            };
+            match &host.port {
+                Some(port) => println!("  ~/.ssh/config: {} → {}:{}", alias, target, port),
+                None => println!("  ~/.ssh/config: {} → {}", alias, target),
+            }
+            if let Some(identity_file) = &host.identity_file {
+                println!("  IdentityFile: {}", identity_file);
+            }
+        }
+        None => println!(
+            "⚠ Alias '{}' has no matching Host block in ~/.ssh/config",
+            alias
+        ),
+    }
+}
+
+/// Resolve `HostName`/`User`/`Port`/`IdentityFile` for an alias.
+///
+/// Follows ssh_config semantics: the first value obtained for each keyword wins,
+/// and `Host` patterns support `*`, `?` and `!` negation. Returns `None` when no
+/// `Host` block other than a catch-all `*` matches the alias.
+fn resolve_ssh_config_host(content: &str, alias: &str) -> Option<SshConfigHost> {
+    let mut resolved = SshConfigHost::default();
+    let mut in_matching_block = true;
+    let mut matched_specific = false;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (keyword, value) = match line.split_once(|c: char| c.is_whitespace() || c == '=') {
+            Some((k, v)) => (k, v.trim().trim_start_matches('=').trim()),
+            None => continue,
+        };
+        let value = value.trim_matches('"');
+
+        match keyword.to_lowercase().as_str() {
+            "host" => {
+                let patterns: Vec<&str> = value.split_whitespace().collect();
+                in_matching_block = host_patterns_match(&patterns, alias);
+                if in_matching_block && patterns.iter().any(|p| *p != "*") {
+                    matched_specific = true;
+                }
+            }
+            "match" => in_matching_block = false,
+            "hostname" if in_matching_block => {
+                resolved.hostname.get_or_insert_with(|| value.to_string());
+            }
+            "user" if in_matching_block => {
+                resolved.user.get_or_insert_with(|| value.to_string());
+            }
+            "port" if in_matching_block => {
+                resolved.port.get_or_insert_with(|| value.to_string());
+            }
+            "identityfile" if in_matching_block => {
+                resolved
+                    .identity_file
+                    .get_or_insert_with(|| value.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    matched_specific.then_some(resolved)
+}
+
+fn host_patterns_match(patterns: &[&str], host: &str) -> bool {
+    let mut matched = false;
+    for pattern in patterns {
+        if let Some(negated) = pattern.strip_prefix('!') {
+            if glob_match(negated, host) {
+                return false;
+            }
+        } else if glob_match(pattern, host) {
+            matched = true;
+        }
+    }
+    matched
+}
+
+/// Resolve a `proxy_jump` card into a value for `ssh -J`.
+///
+/// Each comma-separated hop may be a literal `user@host` or the name/alias of
+/// another hand in the deck. Hands are replaced by their `host` card (or first
+/// alias); those holding a private key are returned so it can be loaded into
+/// ssh-agent before connecting.
+fn resolve_proxy_jump<'a>(
+    deck: &'a Deck,
+    proxy_jump: &str,
+) -> Result<(String, Vec<(String, &'a Hand)>)> {
+    let mut hops = Vec::new();
+    let mut jump_hands = Vec::new();
+
+    for hop in proxy_jump
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+    {
+        let Some(jump_hand_name) = find_hand_by_name_or_alias(deck, hop) else {
+            hops.push(hop.to_string());
+            continue;
+        };
+        let jump_hand = deck.get_hand(&jump_hand_name)?;
+
+        let jump_target = if hop.contains('@') {
+            hop.to_string()
+        } else {
+            jump_hand
+                .cards
+                .get("host")
+                .or_else(|| jump_hand.cards.get("alias"))
+                .and_then(|csv| csv.split(',').map(|s| s.trim()).next())
+                .map(|s| s.to_string())
+                .with_context(|| {
+                    format!(
+                        "Jump hand '{}' has no 'host' or 'alias' card",
+                        jump_hand_name
+                    )
+                })?
+        };
+
+        if let Some(private_key) = jump_hand.cards.get("private_key") {
+            validate_private_key(private_key)?;
+            jump_hands.push((jump_hand_name, jump_hand));
+        }
+
+        hops.push(jump_target);
+    }
+
+    if hops.is_empty() {
+        anyhow::bail!("Card 'proxy_jump' is empty");
+    }
+
+    Ok((hops.join(","), jump_hands))
+}
+
+/// Normalize a host key to `keytype base64key`, dropping a leading known_hosts host field
+fn normalize_host_key(line: &str) -> Result<String> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let key_fields = match fields.as_slice() {
+        [key_type, key, ..] if is_host_key_type(key_type) => [*key_type, *key],
+        [_, key_type, key, ..] if is_host_key_type(key_type) => [*key_type, *key],
+        _ => anyhow::bail!(
+            "Invalid host key '{}'. Expected a known_hosts line or 'keytype base64key'",
+            line
+        ),
+    };
+    Ok(key_fields.join(" "))
+}
+
+fn is_host_key_type(field: &str) -> bool {
+    field.starts_with("ssh-") || field.starts_with("ecdsa-") || field.starts_with("sk-")
+}
+
+/// Look up the host key(s) the user accepted for a target in ~/.ssh/known_hosts
+fn capture_host_key(ssh_target: &str) -> Option<String> {
+    let host = ssh_target.rsplit('@').next().unwrap_or(ssh_target);
+
+    let (hostname, port) = std::env::var("HOME")
+        .ok()
+        .and_then(|home| std::fs::read_to_string(Path::new(&home).join(".ssh/config")).ok())
+        .and_then(|content| resolve_ssh_config_host(&content, host))
+        .map(|resolved| {
+            (
+                resolved.hostname.unwrap_or_else(|| host.to_string()),
+                resolved.port,
+            )
+        })
+        .unwrap_or_else(|| (host.to_string(), None));
+
+    let lookup = match port.as_deref() {
+        Some(port) if port != "22" => format!("[{}]:{}", hostname, port),
+        _ => hostname,
+    };
+
+    let output = Command::new("ssh-keygen")
+        .args(["-F", &lookup])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let keys: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.trim().is_empty())
+        .filter_map(|line| normalize_host_key(line).ok())
+        .collect();
+
+    if keys.is_empty() {
+        None
+    } else {
+        Some(keys.join("\n"))
+    }
+}
+
+fn execute_with_password(
+    program: &str,
+    args: &[String],
     password: &str,
 ) -> Result<std::process::ExitStatus> {
     if !is_sshpass_available() {
@@ -383,22 +1037,82 @@ fn execute_ssh_with_password(
         );
     }
 
-    println!("✓ Using password authentication");
+    status!("✓ Using password authentication");
 
     Command::new("sshpass")
         .arg("-p")
         .arg(password)
-        .arg("ssh")
-        .arg(ssh_target)
-        .args(ssh_args)
+        .arg(program)
+        .args(args)
         .status()
         .context("Failed to execute sshpass command")
 }
 
-fn is_sshpass_available() -> bool {
+pub fn is_sshpass_available() -> bool {
     Command::new("which")
         .arg("sshpass")
         .output()
         .map(|output| output.status.success())
         .unwrap_or(false)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SSH_CONFIG: &str = "\
+Host prod prod-*
+    HostName prod.example.com
+    User deploy
+    Port 2222
+    IdentityFile ~/.ssh/prod_key
+
+Host !prod-legacy staging
+    HostName=staging.example.com
+
+Host *
+    User fallback
+    IdentityFile ~/.ssh/id_ed25519
+";
+
+    #[test]
+    fn test_resolve_ssh_config_host() {
+        let host = resolve_ssh_config_host(SSH_CONFIG, "prod").unwrap();
+        assert_eq!(host.hostname.as_deref(), Some("prod.example.com"));
+        assert_eq!(host.user.as_deref(), Some("deploy"));
+        assert_eq!(host.port.as_deref(), Some("2222"));
+        assert_eq!(host.identity_file.as_deref(), Some("~/.ssh/prod_key"));
+    }
+
+    #[test]
+    fn test_resolve_ssh_config_wildcard_and_defaults() {
+        let host = resolve_ssh_config_host(SSH_CONFIG, "prod-db").unwrap();
+        assert_eq!(host.hostname.as_deref(), Some("prod.example.com"));
+
+        let host = resolve_ssh_config_host(SSH_CONFIG, "staging").unwrap();
+        assert_eq!(host.hostname.as_deref(), Some("staging.example.com"));
+        assert_eq!(host.user.as_deref(), Some("fallback"));
+    }
+
+    #[test]
+    fn test_resolve_ssh_config_unknown_alias() {
+        assert!(resolve_ssh_config_host(SSH_CONFIG, "prdo").is_none());
+    }
+
+    #[test]
+    fn test_normalize_host_key() {
+        assert_eq!(
+            normalize_host_key("ssh-ed25519 AAAAC3Nza").unwrap(),
+            "ssh-ed25519 AAAAC3Nza"
+        );
+        assert_eq!(
+            normalize_host_key("prod.example.com ssh-ed25519 AAAAC3Nza comment").unwrap(),
+            "ssh-ed25519 AAAAC3Nza"
+        );
+        assert_eq!(
+            normalize_host_key("|1|abc=|def= ecdsa-sha2-nistp256 AAAAE2Vj").unwrap(),
+            "ecdsa-sha2-nistp256 AAAAE2Vj"
+        );
+        assert!(normalize_host_key("not a host key").is_err());
+    }
+}
@@ -2,7 +2,7 @@ use anyhow::{Context, Result};
 use std::path::Path;
 use std::process::Command;
 
-use crate::domain::TemplateEngine;
+use crate::domain::{HcError, TemplateEngine};
 use crate::infrastructure::KeyringManager;
 use crate::multi_deck_context::MultiDeckContext;
 
@@ -18,7 +18,7 @@ pub fn handle_inject(
         .inner
         .deck
         .get_hand(card_name)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+        .map_err(HcError::from)?;
 
     let rendered = TemplateEngine::render(template, hand)?;
     println!("{}", rendered);
@@ -42,7 +42,7 @@ pub fn handle_run(
         .inner
         .deck
         .get_hand(card_name)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+        .map_err(HcError::from)?;
 
     let mut cmd = Command::new(&command[0]);
     cmd.args(&command[1..]);
@@ -1,11 +1,15 @@
-use anyhow::Result;
-use copypasta::{ClipboardContext, ClipboardProvider};
+use anyhow::{Context, Result};
+use console::Term;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
 use crate::cli::commands::TotpCommands;
-use crate::domain::TotpService;
+use crate::cli::output::status;
+use crate::domain::{TotpParams, TotpService};
+use crate::handlers::password::{clear_notice, copy_to_clipboard_with_clear};
 use crate::infrastructure::KeyringManager;
 use crate::multi_deck_context::MultiDeckContext;
 
@@ -16,22 +20,103 @@ pub fn handle_totp(
     config_dir: &Path,
 ) -> Result<()> {
     match subcommand {
-        TotpCommands::Add { card, secret } => {
-            handle_totp_add(&card, &secret, deck_name, keyring, config_dir)
+        TotpCommands::Add {
+            card,
+            secret,
+            qr_image,
+            algorithm,
+            digits,
+            period,
+        } => {
+            let (secret, mut params) = match (secret, qr_image) {
+                (_, Some(path)) => {
+                    TotpService::parse_otpauth_uri(&decode_qr_image(Path::new(&path))?)?
+                }
+                (Some(secret), None) => parse_secret_arg(secret)?,
+                (None, None) => anyhow::bail!("Specify a TOTP secret or --qr-image <path>"),
+            };
+            if let Some(algorithm) = algorithm {
+                params.algorithm = algorithm;
+            }
+            if let Some(digits) = digits {
+                params.digits = digits;
+            }
+            if let Some(period) = period {
+                params.period = period;
+            }
+            handle_totp_add(&card, &secret, &params, deck_name, keyring, config_dir)
         }
-        TotpCommands::Get { card } => handle_totp_get(&card, deck_name, keyring, config_dir),
+        TotpCommands::Get { card, watch, skew } => {
+            handle_totp_get(&card, watch, skew, deck_name, keyring, config_dir)
+        }
+        TotpCommands::Validate { secret } => handle_totp_validate(secret),
         TotpCommands::Rm { card } => handle_totp_rm(&card, deck_name, keyring, config_dir),
     }
 }
 
+/// Accept either a raw base32 secret or an otpauth:// URI
+fn parse_secret_arg(secret: String) -> Result<(String, TotpParams)> {
+    if secret.starts_with("otpauth://") {
+        TotpService::parse_otpauth_uri(&secret)
+    } else {
+        Ok((secret, TotpParams::default()))
+    }
+}
+
+fn handle_totp_validate(secret: String) -> Result<()> {
+    let (secret, params) = parse_secret_arg(secret).context("Invalid TOTP secret")?;
+    TotpService::validate_secret(&secret).context("Invalid TOTP secret")?;
+    params.validate()?;
+
+    let code = TotpService::generate_code(&secret, &params)?;
+    let remaining = TotpService::get_remaining_seconds(params.period);
+
+    status!(
+        "✓ Valid TOTP secret ({}, {} digits, {}s period)",
+        params.algorithm,
+        params.digits,
+        params.period
+    );
+    println!("TOTP Code: {} (valid for {} seconds)", code, remaining);
+    status!("Compare it with your authenticator app; nothing was saved.");
+
+    Ok(())
+}
+
+fn decode_qr_image(path: &Path) -> Result<String> {
+    let image = image::open(path)
+        .with_context(|| format!("Failed to read image: {}", path.display()))?
+        .to_luma8();
+
+    let mut prepared = rqrr::PreparedImage::prepare_from_greyscale(
+        image.width() as usize,
+        image.height() as usize,
+        |x, y| image.get_pixel(x as u32, y as u32).0[0],
+    );
+
+    let grids = prepared.detect_grids();
+    if grids.is_empty() {
+        anyhow::bail!("No QR code found in {}", path.display());
+    }
+
+    grids
+        .iter()
+        .filter_map(|grid| grid.decode().ok())
+        .map(|(_, content)| content)
+        .find(|content| content.starts_with("otpauth://"))
+        .ok_or_else(|| anyhow::anyhow!("QR code in {} is not an otpauth:// URI", path.display()))
+}
+
 fn handle_totp_add(
     service_name: &str,
     secret: &str,
+    params: &TotpParams,
     deck_name: Option<&str>,
     keyring: &KeyringManager,
     config_dir: &Path,
 ) -> Result<()> {
     TotpService::validate_secret(secret)?;
+    params.validate()?;
 
     let mut ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
 
@@ -46,19 +131,22 @@ fn handle_totp_add(
         );
     }
 
-    totp_entry
-        .cards
-        .insert(service_name.to_string(), secret.to_string());
+    totp_entry.cards.insert(
+        service_name.to_string(),
+        TotpService::to_stored_value(service_name, secret, params),
+    );
     totp_entry.updated_at = chrono::Utc::now();
 
     ctx.save()?;
-    println!("✓ TOTP secret for '{}' added", service_name);
+    status!("✓ TOTP secret for '{}' added", service_name);
 
     Ok(())
 }
 
 fn handle_totp_get(
     service_name: &str,
+    watch: bool,
+    skew: Option<u64>,
     deck_name: Option<&str>,
     keyring: &KeyringManager,
     config_dir: &Path,
@@ -73,25 +161,20 @@ fn handle_totp_get(
             anyhow::bail!("TOTP secret for '{}' is empty", service_name);
         }
 
-        match TotpService::generate_code(secret) {
+        let (secret, params) = TotpService::from_stored_value(secret)?;
+        if watch {
+            return watch_totp(&secret, &params);
+        }
+        match TotpService::generate_code(&secret, &params) {
             Ok(code) => {
-                let remaining = TotpService::get_remaining_seconds();
+                let remaining = TotpService::get_remaining_seconds(params.period);
                 println!("\nTOTP Code: {} (valid for {} seconds)", code, remaining);
+                if let Some(skew) = skew {
+                    print_adjacent_codes(&secret, &params, skew)?;
+                }
 
-                let mut clipboard_ctx = ClipboardContext::new()
-                    .map_err(|e| anyhow::anyhow!("Failed to initialize clipboard: {:?}", e))?;
-                clipboard_ctx
-                    .set_contents(code.clone())
-                    .map_err(|e| anyhow::anyhow!("Failed to copy to clipboard: {:?}", e))?;
-
-                println!("✓ Copied to clipboard (will clear in 30 seconds)");
-
-                thread::spawn(move || {
-                    thread::sleep(Duration::from_secs(30));
-                    if let Ok(mut ctx) = ClipboardContext::new() {
-                        let _ = ctx.set_contents(String::new());
-                    }
-                });
+                copy_to_clipboard_with_clear(&code)?;
+                status!("✓ Copied to clipboard ({})", clear_notice(true));
             }
             Err(e) => {
                 anyhow::bail!("Failed to generate TOTP code: {}", e);
@@ -104,6 +187,64 @@ fn handle_totp_get(
     Ok(())
 }
 
+/// Print the codes for the windows within `skew` seconds either side of now,
+/// for when the server's clock disagrees with ours.
+fn print_adjacent_codes(secret: &str, params: &TotpParams, skew: u64) -> Result<()> {
+    let now = chrono::Utc::now().timestamp() as u64;
+    let windows = skew.div_ceil(params.period);
+
+    for offset in (1..=windows).rev() {
+        let delta = offset.saturating_mul(params.period);
+        let code = TotpService::generate_code_at(secret, params, now.saturating_sub(delta))?;
+        println!("  -{}s: {}", delta, code);
+    }
+    for offset in 1..=windows {
+        let delta = offset.saturating_mul(params.period);
+        let code = TotpService::generate_code_at(secret, params, now.saturating_add(delta))?;
+        println!("  +{}s: {}", delta, code);
+    }
+
+    Ok(())
+}
+
+/// Redraw the code and a countdown bar in place until Ctrl-C, copying
+/// each new code to the clipboard as the window rolls over.
+fn watch_totp(secret: &str, params: &TotpParams) -> Result<()> {
+    const BAR_WIDTH: u64 = 30;
+
+    let running = Arc::new(AtomicBool::new(true));
+    let flag = running.clone();
+    ctrlc::set_handler(move || flag.store(false, Ordering::SeqCst))
+        .context("Failed to install Ctrl-C handler")?;
+
+    let term = Term::stdout();
+    let mut current = String::new();
+    while running.load(Ordering::SeqCst) {
+        let code = TotpService::generate_code(secret, params)
+            .map_err(|e| anyhow::anyhow!("Failed to generate TOTP code: {}", e))?;
+        if code != current {
+            copy_to_clipboard_with_clear(&code)?;
+            current = code;
+        }
+
+        let remaining = TotpService::get_remaining_seconds(params.period);
+        let filled = (remaining * BAR_WIDTH / params.period) as usize;
+        term.clear_line()?;
+        term.write_str(&format!(
+            "TOTP Code: {}  [{}{}] {:>2}s",
+            current,
+            "█".repeat(filled),
+            "░".repeat(BAR_WIDTH as usize - filled),
+            remaining
+        ))?;
+        thread::sleep(Duration::from_millis(200));
+    }
+
+    term.clear_line()?;
+    status!("✓ Stopped watching ({})", clear_notice(true));
+    Ok(())
+}
+
 fn handle_totp_rm(
     service_name: &str,
     deck_name: Option<&str>,
@@ -119,9 +260,9 @@ fn handle_totp_rm(
     if totp_entry.cards.remove(service_name).is_some() {
         totp_entry.updated_at = chrono::Utc::now();
         ctx.save()?;
-        println!("✓ TOTP secret for '{}' removed", service_name);
+        status!("✓ TOTP secret for '{}' removed", service_name);
     } else {
-        println!("⚠ No TOTP secret found for service '{}'", service_name);
+        eprintln!("⚠ No TOTP secret found for service '{}'", service_name);
     }
 
     Ok(())
@@ -1,59 +1,239 @@
 use anyhow::{Context, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
 use std::path::Path;
 
 use crate::cli::input;
-use crate::domain::Hand;
+use crate::cli::output::status;
+use crate::config::Config;
+use crate::deck_context::skip_write_for_dry_run;
+use crate::domain::{
+    format_env_exports, sanitize_env_key, Deck, ExportFormat, Hand, HcError, MergeOutcome,
+    MergeStrategy,
+};
 use crate::infrastructure::{
-    decrypt_for_import, encrypt_for_export, require_biometric_auth, KeyringManager,
+    decrypt_for_import, encrypt_for_export, require_biometric_auth, write_private_file,
+    DeckRegistry, KeyringManager,
 };
 use crate::multi_deck_context::MultiDeckContext;
 
+const ARCHIVE_VERSION: u32 = 1;
+
+/// Contents of an `hc deck export-all` archive (encrypted as a whole).
+#[derive(Serialize, Deserialize)]
+struct DeckArchive {
+    version: u32,
+    decks: Vec<ArchivedDeck>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ArchivedDeck {
+    name: String,
+    hands: Vec<Hand>,
+}
+
+/// Options for `hc export`.
+pub struct ExportOptions {
+    pub format: ExportFormat,
+    pub insecure: bool,
+    pub hand: Option<String>,
+    pub exclude_totp: bool,
+    pub exclude: Vec<String>,
+    pub assume_yes: bool,
+}
+
+/// Export the current deck. With `file` as `None` the encrypted blob is written
+/// to stdout as base64 and all messages go to stderr.
 pub fn handle_export(
-    file: &str,
+    file: Option<&str>,
+    options: &ExportOptions,
     deck_name: Option<&str>,
     keyring: &KeyringManager,
     config_dir: &Path,
 ) -> Result<()> {
+    if options.format == ExportFormat::Env && !options.insecure {
+        anyhow::bail!("--format env writes secrets in plaintext; pass --insecure to allow it");
+    }
+    if options.hand.is_some() && options.format != ExportFormat::Env {
+        anyhow::bail!("--hand is only supported with --format env");
+    }
+
     let ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
 
     // Require Touch ID for export operations
     require_biometric_auth(&ctx.inner.config, "Export entire deck")?;
 
+    for name in &options.exclude {
+        if ctx.inner.deck.get_hand(name).is_err() {
+            eprintln!("⚠ No hand named '{}' to exclude", name);
+        }
+    }
+
     let hands = ctx.inner.deck.list_hands();
+    let total = hands.len();
+    let export_data: Vec<&Hand> = hands
+        .into_iter()
+        .filter(|hand| !(options.exclude_totp && hand.name() == "totp"))
+        .filter(|hand| !options.exclude.iter().any(|name| name == hand.name()))
+        .collect();
+    let excluded = match total - export_data.len() {
+        0 => String::new(),
+        n => format!(" ({} excluded)", n),
+    };
+
+    if options.format == ExportFormat::Env {
+        let vars = match options.hand.as_deref() {
+            Some(name) => {
+                let hand = ctx
+                    .inner
+                    .deck
+                    .get_hand(name)
+                    .map_err(|_| anyhow::anyhow!("Hand '{}' not found", name))?;
+                hand_env_vars(hand, None)
+            }
+            None => export_data
+                .iter()
+                .flat_map(|hand| hand_env_vars(hand, Some(hand.name())))
+                .collect(),
+        };
+        return write_env_export(file, &vars, options.assume_yes, &ctx.deck_name);
+    }
 
-    let export_data: Vec<&Hand> = hands.into_iter().collect();
     let json = serde_json::to_string_pretty(&export_data).context("Failed to serialize hands")?;
 
-    println!("\nSet a password to encrypt the export file:");
+    eprintln!("\nSet a password to encrypt the export file:");
     let password = input::prompt_export_password()?;
 
     let encrypted = encrypt_for_export(json.as_bytes(), &password)
         .map_err(|e| anyhow::anyhow!("Failed to encrypt export: {}", e))?;
 
-    std::fs::write(file, &encrypted).context("Failed to write export file")?;
+    match file {
+        Some(file) if skip_write_for_dry_run(Path::new(file)) => {}
+        Some(file) => {
+            std::fs::write(file, &encrypted).context("Failed to write export file")?;
+            status!(
+                "\n✓ Exported {} hands{} from deck '{}' to {} (encrypted)",
+                export_data.len(),
+                excluded,
+                ctx.deck_name,
+                file
+            );
+        }
+        None => {
+            let mut stdout = std::io::stdout().lock();
+            writeln!(stdout, "{}", BASE64.encode(&encrypted))
+                .and_then(|_| stdout.flush())
+                .context("Failed to write export to stdout")?;
+            eprintln!(
+                "✓ Exported {} hands{} from deck '{}' to stdout (encrypted, base64)",
+                export_data.len(),
+                excluded,
+                ctx.deck_name
+            );
+        }
+    }
 
-    println!(
-        "\n✓ Exported {} hands from deck '{}' to {} (encrypted)",
-        export_data.len(),
-        ctx.deck_name,
-        file
-    );
+    Ok(())
+}
 
+/// `(KEY, value)` pairs for a hand's cards, sorted by key. Keys are upper-cased
+/// and sanitized, and prefixed with the hand name when `prefix` is given.
+fn hand_env_vars(hand: &Hand, prefix: Option<&str>) -> Vec<(String, String)> {
+    let mut vars: Vec<(String, String)> = hand
+        .cards
+        .iter()
+        .map(|(key, value)| {
+            let raw_key = match prefix {
+                Some(prefix) => format!("{}_{}", prefix, key),
+                None => key.clone(),
+            };
+            (sanitize_env_key(&raw_key).to_uppercase(), value.clone())
+        })
+        .collect();
+    vars.sort();
+    vars
+}
+
+fn write_env_export(
+    file: Option<&str>,
+    vars: &[(String, String)],
+    assume_yes: bool,
+    deck_name: &str,
+) -> Result<()> {
+    let target = file.unwrap_or("stdout");
+    if !assume_yes
+        && !input::prompt_confirm(&format!(
+            "Write {} secrets from deck '{}' in plaintext to {}?",
+            vars.len(),
+            deck_name,
+            target
+        ))?
+    {
+        anyhow::bail!("Export cancelled");
+    }
+
+    let text = format_env_exports(vars);
+    match file {
+        Some(file) if skip_write_for_dry_run(Path::new(file)) => return Ok(()),
+        Some(file) => write_private_file(Path::new(file), &text)?,
+        None => {
+            let mut stdout = std::io::stdout().lock();
+            stdout
+                .write_all(text.as_bytes())
+                .and_then(|_| stdout.flush())
+                .context("Failed to write export to stdout")?;
+        }
+    }
+
+    eprintln!(
+        "⚠ Exported {} variables from deck '{}' to {} in PLAINTEXT",
+        vars.len(),
+        deck_name,
+        target
+    );
     Ok(())
 }
 
+/// Read an import payload from a file, or base64 from stdin when `file` is `-`.
+/// Files holding base64 text (e.g. a saved `hc export -`) are decoded as well.
+fn read_import_payload(file: &str) -> Result<Vec<u8>> {
+    if file == "-" {
+        let mut text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut text)
+            .context("Failed to read import data from stdin")?;
+        return decode_base64_payload(&text)
+            .ok_or_else(|| anyhow::anyhow!("Import data on stdin is not valid base64"));
+    }
+
+    let data = std::fs::read(file).context("Failed to read import file")?;
+    Ok(std::str::from_utf8(&data)
+        .ok()
+        .and_then(decode_base64_payload)
+        .unwrap_or(data))
+}
+
+fn decode_base64_payload(text: &str) -> Option<Vec<u8>> {
+    let compact: String = text.split_whitespace().collect();
+    if compact.is_empty() {
+        return None;
+    }
+    BASE64.decode(compact).ok()
+}
+
 pub fn handle_import(
     file: &str,
-    overwrite: bool,
+    strategy: MergeStrategy,
     deck_name: Option<&str>,
     keyring: &KeyringManager,
     config_dir: &Path,
 ) -> Result<()> {
     let mut ctx = MultiDeckContext::load(deck_name, keyring, config_dir)?;
 
-    let encrypted_data = std::fs::read(file).context("Failed to read import file")?;
+    let encrypted_data = read_import_payload(file)?;
 
-    println!("\nEnter the password used to encrypt this export:");
+    eprintln!("\nEnter the password used to encrypt this export:");
     let password = input::prompt_import_password()?;
 
     let decrypted = decrypt_for_import(&encrypted_data, &password)
@@ -61,36 +241,177 @@ pub fn handle_import(
 
     let json = String::from_utf8(decrypted).context("Failed to decode decrypted data as UTF-8")?;
     let hands: Vec<Hand> = serde_json::from_str(&json).context("Failed to parse import file")?;
+    for hand in &hands {
+        hand.validate().map_err(HcError::from)?;
+    }
+
+    let counts = merge_imported(&mut ctx.inner.deck, hands, strategy);
+
+    ctx.save()?;
+
+    status!("\n✓ Import complete to deck '{}':", ctx.deck_name);
+    counts.print(strategy);
+
+    Ok(())
+}
+
+/// How many imported hands ended up in each merge outcome
+#[derive(Default)]
+struct ImportCounts {
+    imported: usize,
+    overwritten: usize,
+    merged: usize,
+    skipped: usize,
+}
 
-    let mut imported = 0;
-    let mut overwritten = 0;
-    let mut skipped = 0;
+impl ImportCounts {
+    fn print(&self, strategy: MergeStrategy) {
+        println!("  • {} hands imported", self.imported);
+        if self.overwritten > 0 {
+            println!("  • {} hands overwritten", self.overwritten);
+        }
+        if self.merged > 0 {
+            println!("  • {} hands merged", self.merged);
+        }
+        if self.skipped > 0 {
+            if strategy == MergeStrategy::Skip {
+                println!(
+                    "  • {} hands skipped (use --strategy to merge)",
+                    self.skipped
+                );
+            } else {
+                println!("  • {} hands skipped", self.skipped);
+            }
+        }
+    }
+}
+
+/// Merge `hands` into `deck` with `strategy`, reporting each non-trivial outcome
+fn merge_imported(deck: &mut Deck, hands: Vec<Hand>, strategy: MergeStrategy) -> ImportCounts {
+    let mut counts = ImportCounts::default();
 
     for hand in hands {
-        match ctx.inner.deck.import_hand(hand.clone(), overwrite) {
-            Ok(was_overwritten) => {
-                if was_overwritten {
-                    overwritten += 1;
-                } else {
-                    imported += 1;
-                }
+        let name = hand.name().to_string();
+        match deck.merge_hand(hand, strategy) {
+            MergeOutcome::Added => counts.imported += 1,
+            MergeOutcome::Overwritten => {
+                println!("  Overwrote '{}'", name);
+                counts.overwritten += 1;
             }
-            Err(_) => {
-                println!("  Skipped '{}' (already exists)", hand.name());
-                skipped += 1;
+            MergeOutcome::Merged => {
+                println!("  Merged cards into '{}'", name);
+                counts.merged += 1;
+            }
+            MergeOutcome::KeptExisting => {
+                println!("  Kept existing '{}' (newer than import)", name);
+                counts.skipped += 1;
+            }
+            MergeOutcome::Skipped => {
+                println!("  Skipped '{}' (already exists)", name);
+                counts.skipped += 1;
             }
         }
     }
 
-    ctx.save()?;
+    counts
+}
 
-    println!("\n✓ Import complete to deck '{}':", ctx.deck_name);
-    println!("  • {} hands imported", imported);
-    if overwritten > 0 {
-        println!("  • {} hands overwritten", overwritten);
+pub fn handle_export_all(file: &str, keyring: &KeyringManager, config_dir: &Path) -> Result<()> {
+    let registry = DeckRegistry::load(config_dir)?;
+    let decks = registry.list_decks()?;
+
+    if decks.is_empty() {
+        anyhow::bail!("No decks found. Create one with 'hc deck create <name>'");
     }
-    if skipped > 0 {
-        println!("  • {} hands skipped (use --overwrite to replace)", skipped);
+
+    let config = Config::load(config_dir)?;
+    require_biometric_auth(&config, "Export all decks")?;
+
+    let mut archive = DeckArchive {
+        version: ARCHIVE_VERSION,
+        decks: Vec::new(),
+    };
+
+    for deck in &decks {
+        println!("Loading deck '{}'...", deck.name);
+        let ctx = MultiDeckContext::load(Some(&deck.name), keyring, config_dir)?;
+        let hands: Vec<Hand> = ctx.inner.deck.list_hands().into_iter().cloned().collect();
+        archive.decks.push(ArchivedDeck {
+            name: ctx.deck_name,
+            hands,
+        });
+    }
+
+    let json = serde_json::to_string_pretty(&archive).context("Failed to serialize decks")?;
+
+    println!("\nSet a password to encrypt the backup archive:");
+    let password = input::prompt_export_password()?;
+
+    let encrypted = encrypt_for_export(json.as_bytes(), &password)
+        .map_err(|e| anyhow::anyhow!("Failed to encrypt export: {}", e))?;
+
+    std::fs::write(file, &encrypted).context("Failed to write export file")?;
+
+    status!(
+        "\n✓ Exported {} deck(s) to {} (encrypted)",
+        archive.decks.len(),
+        file
+    );
+    for deck in &archive.decks {
+        println!("  • {} ({} hands)", deck.name, deck.hands.len());
+    }
+
+    Ok(())
+}
+
+pub fn handle_import_all(
+    file: &str,
+    strategy: MergeStrategy,
+    keyring: &KeyringManager,
+    config_dir: &Path,
+) -> Result<()> {
+    let encrypted_data = std::fs::read(file).context("Failed to read import file")?;
+
+    println!("\nEnter the password used to encrypt this archive:");
+    let password = input::prompt_import_password()?;
+
+    let decrypted = decrypt_for_import(&encrypted_data, &password)
+        .map_err(|_| anyhow::anyhow!("Failed to decrypt: wrong password or corrupted file"))?;
+
+    let json = String::from_utf8(decrypted).context("Failed to decode decrypted data as UTF-8")?;
+    let archive: DeckArchive =
+        serde_json::from_str(&json).context("Failed to parse backup archive")?;
+
+    if archive.version > ARCHIVE_VERSION {
+        anyhow::bail!(
+            "Unsupported archive version {} (this hc supports up to {})",
+            archive.version,
+            ARCHIVE_VERSION
+        );
+    }
+
+    for archived in archive.decks {
+        let registry = DeckRegistry::load(config_dir)?;
+        if registry.get_deck(&archived.name).is_err() {
+            println!("\nDeck '{}' does not exist; creating it.", archived.name);
+            crate::handlers::deck_management::handle_create(
+                archived.name.clone(),
+                keyring,
+                config_dir,
+            )?;
+        }
+
+        let mut ctx = MultiDeckContext::load(Some(&archived.name), keyring, config_dir)?;
+
+        for hand in &archived.hands {
+            hand.validate().map_err(HcError::from)?;
+        }
+        let counts = merge_imported(&mut ctx.inner.deck, archived.hands, strategy);
+
+        ctx.save()?;
+
+        status!("✓ Restored deck '{}':", ctx.deck_name);
+        counts.print(strategy);
     }
 
     Ok(())
@@ -95,10 +95,10 @@ pub fn require_biometric_auth(config: &Config, reason: &str) -> Result<()> {
         return Ok(());
     }
 
-    println!("🔐 Authentication required...");
+    eprintln!("🔐 Authentication required...");
     match biometric.authenticate(reason) {
         Ok(true) => {
-            println!("✅ Authenticated");
+            eprintln!("✅ Authenticated");
             Ok(())
         }
         Ok(false) => Err(anyhow::anyhow!("Authentication failed")),
@@ -5,8 +5,14 @@ use aes_gcm::{
 };
 use argon2::{Algorithm, Argon2, Params, Version};
 use rand::RngCore;
+use std::time::{Duration, Instant};
 use zeroize::Zeroize;
 
+/// Argon2id cost used for deck and export keys
+pub const KDF_MEMORY_KIB: u32 = 19 * 1024;
+pub const KDF_ITERATIONS: u32 = 2;
+const KDF_PARALLELISM: u32 = 1;
+
 const NONCE_LEN: usize = 12;
 const MIN_ENCRYPTED_LEN_WITH_KEY: usize = NONCE_LEN + 16;
 
@@ -49,9 +55,7 @@ impl CryptoService for CryptoServiceImpl {
 
         let mut output_key = [0u8; 32];
 
-        let params = Params::new(19 * 1024, 2, 1, Some(32))
-            .map_err(|e| CryptoError::KeyDerivationFailed(e.to_string()))?;
-        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+        let argon2 = argon2id(KDF_MEMORY_KIB, KDF_ITERATIONS)?;
 
         argon2
             .hash_password_into(&combined, salt, &mut output_key)
@@ -72,6 +76,9 @@ impl CryptoService for CryptoServiceImpl {
         let cipher = Aes256Gcm::new_from_slice(derived_key)
             .map_err(|e| CryptoError::CipherInitFailed(e.to_string()))?;
 
+        #[cfg(debug_assertions)]
+        nonce_guard::assert_fresh(derived_key, &nonce_bytes);
+
         let nonce = Nonce::from_slice(&nonce_bytes);
         let ciphertext = cipher
             .encrypt(nonce, data)
@@ -167,12 +174,28 @@ pub fn decrypt_for_import(encrypted_data: &[u8], password: &str) -> Result<Vec<u
     Ok(plaintext)
 }
 
-fn derive_key_from_password(password: &str, salt: &[u8]) -> Result<[u8; 32], CryptoError> {
+fn argon2id(memory_kib: u32, iterations: u32) -> Result<Argon2<'static>, CryptoError> {
+    let params = Params::new(memory_kib, iterations, KDF_PARALLELISM, Some(32))
+        .map_err(|e| CryptoError::KeyDerivationFailed(e.to_string()))?;
+    Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+}
+
+/// Time a single Argon2id derivation at the given cost
+pub fn time_kdf(memory_kib: u32, iterations: u32) -> Result<Duration, CryptoError> {
+    let argon2 = argon2id(memory_kib, iterations)?;
     let mut output_key = [0u8; 32];
 
-    let params = Params::new(19 * 1024, 2, 1, Some(32))
+    let started = Instant::now();
+    argon2
+        .hash_password_into(b"benchmark", &[0u8; 16], &mut output_key)
         .map_err(|e| CryptoError::KeyDerivationFailed(e.to_string()))?;
-    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+    Ok(started.elapsed())
+}
+
+fn derive_key_from_password(password: &str, salt: &[u8]) -> Result<[u8; 32], CryptoError> {
+    let mut output_key = [0u8; 32];
+
+    let argon2 = argon2id(KDF_MEMORY_KIB, KDF_ITERATIONS)?;
 
     argon2
         .hash_password_into(password.as_bytes(), salt, &mut output_key)
@@ -180,3 +203,47 @@ fn derive_key_from_password(password: &str, salt: &[u8]) -> Result<[u8; 32], Cry
 
     Ok(output_key)
 }
+
+/// Debug-build guard against AES-GCM nonce reuse: remembers a digest of every
+/// (key, nonce) pair used in this process and panics on a repeat. Keys are
+/// derived from the deck salt, so this also covers a frozen salt.
+#[cfg(debug_assertions)]
+mod nonce_guard {
+    use sha2::{Digest, Sha256};
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    static SEEN: Mutex<Option<HashSet<[u8; 32]>>> = Mutex::new(None);
+
+    pub(super) fn assert_fresh(key: &[u8; 32], nonce: &[u8]) {
+        assert!(
+            record(key, nonce),
+            "AES-GCM nonce reused with the same key; refusing to encrypt"
+        );
+    }
+
+    /// Returns false if this (key, nonce) pair was already used
+    fn record(key: &[u8; 32], nonce: &[u8]) -> bool {
+        let digest: [u8; 32] = Sha256::new()
+            .chain_update(key)
+            .chain_update(nonce)
+            .finalize()
+            .into();
+        let mut seen = SEEN.lock().unwrap_or_else(|e| e.into_inner());
+        seen.get_or_insert_with(HashSet::new).insert(digest)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_repeated_nonce_is_detected() {
+            let key = [7u8; 32];
+            assert!(record(&key, &[1u8; 12]));
+            assert!(record(&key, &[2u8; 12]));
+            assert!(!record(&key, &[1u8; 12]));
+            assert!(record(&[8u8; 32], &[1u8; 12]));
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use anyhow::{Context, Result};
+use std::ffi::OsString;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How long to wait for another process to finish before giving up.
+const LOCK_WAIT: Duration = Duration::from_secs(3);
+const LOCK_POLL: Duration = Duration::from_millis(100);
+/// A lock older than this is assumed to belong to a crashed process.
+const STALE_AFTER: Duration = Duration::from_secs(600);
+
+/// Advisory `<deck>.lock` file guarding writes to a deck. Released on drop.
+#[derive(Debug)]
+pub struct DeckLock {
+    path: PathBuf,
+}
+
+impl DeckLock {
+    pub fn acquire(deck_path: &Path) -> Result<Self> {
+        Self::acquire_with_timeout(deck_path, LOCK_WAIT)
+    }
+
+    fn acquire_with_timeout(deck_path: &Path, wait: Duration) -> Result<Self> {
+        let path = lock_path(deck_path);
+        let started = SystemTime::now();
+
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file) => {
+                    let _ = writeln!(file, "{}", std::process::id());
+                    return Ok(Self { path });
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    if is_stale(&path) {
+                        let _ = fs::remove_file(&path);
+                        continue;
+                    }
+                    if started.elapsed().unwrap_or_default() >= wait {
+                        anyhow::bail!(
+                            "Deck is in use by another process (lock file: {}). \
+                             If no other hc is running, remove the lock file and retry.",
+                            path.display()
+                        );
+                    }
+                    thread::sleep(LOCK_POLL);
+                }
+                Err(e) => {
+                    return Err(e)
+                        .with_context(|| format!("Failed to create lock file: {}", path.display()))
+                }
+            }
+        }
+    }
+}
+
+impl Drop for DeckLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn lock_path(deck_path: &Path) -> PathBuf {
+    let mut name = OsString::from(deck_path.as_os_str());
+    name.push(".lock");
+    PathBuf::from(name)
+}
+
+fn is_stale(path: &Path) -> bool {
+    let age = fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok());
+    if age.is_some_and(|age| age > STALE_AFTER) {
+        return true;
+    }
+
+    // Where /proc is available, a lock whose owner has exited is stale too.
+    let proc = Path::new("/proc");
+    if !proc.join("self").exists() {
+        return false;
+    }
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| content.trim().parse::<u32>().ok())
+        .is_some_and(|pid| !proc.join(pid.to_string()).exists())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_held_lock_blocks_second_writer() {
+        let dir = tempfile::tempdir().unwrap();
+        let deck_path = dir.path().join("work.enc");
+
+        let lock = DeckLock::acquire_with_timeout(&deck_path, Duration::ZERO).unwrap();
+        assert!(dir.path().join("work.enc.lock").exists());
+
+        let err = DeckLock::acquire_with_timeout(&deck_path, Duration::ZERO).unwrap_err();
+        assert!(err.to_string().contains("in use by another process"));
+
+        drop(lock);
+        assert!(!dir.path().join("work.enc.lock").exists());
+        assert!(DeckLock::acquire_with_timeout(&deck_path, Duration::ZERO).is_ok());
+    }
+
+    #[test]
+    fn test_stale_lock_is_cleaned_up() {
+        let dir = tempfile::tempdir().unwrap();
+        let deck_path = dir.path().join("work.enc");
+        let lock_file = dir.path().join("work.enc.lock");
+
+        let file = fs::File::create(&lock_file).unwrap();
+        file.set_modified(SystemTime::now() - STALE_AFTER * 2)
+            .unwrap();
+        drop(file);
+
+        let _lock = DeckLock::acquire_with_timeout(&deck_path, Duration::ZERO).unwrap();
+        assert_eq!(
+            fs::read_to_string(&lock_file).unwrap().trim(),
+            std::process::id().to_string()
+        );
+    }
+}
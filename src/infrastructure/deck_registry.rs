@@ -4,12 +4,31 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::config::Config;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeckMetadata {
     pub name: String,
     pub path: PathBuf,
     pub created_at: DateTime<Local>,
     pub last_accessed: DateTime<Local>,
+    #[serde(default, skip_serializing_if = "DeckConfigOverrides::is_empty")]
+    pub overrides: DeckConfigOverrides,
+}
+
+/// Per-deck settings that take precedence over the global config
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeckConfigOverrides {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_timeout_minutes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enable_biometric: Option<bool>,
+}
+
+impl DeckConfigOverrides {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
 }
 
 impl DeckMetadata {
@@ -20,6 +39,7 @@ impl DeckMetadata {
             path,
             created_at: now,
             last_accessed: now,
+            overrides: DeckConfigOverrides::default(),
         }
     }
 
@@ -28,6 +48,25 @@ impl DeckMetadata {
     }
 }
 
+/// Marker file selecting a deck for a directory tree, like `.nvmrc`
+pub const LOCAL_DECK_FILE: &str = ".holecard-deck";
+/// Marker name accepted for compatibility with the older "vault" naming
+const LEGACY_LOCAL_DECK_FILE: &str = ".holecard-vault";
+
+/// Walk up from `start` looking for a local deck marker. Returns the deck
+/// name and the marker path.
+pub fn find_local_deck(start: &Path) -> Option<(String, PathBuf)> {
+    start.ancestors().find_map(|dir| {
+        [LOCAL_DECK_FILE, LEGACY_LOCAL_DECK_FILE]
+            .iter()
+            .map(|file| dir.join(file))
+            .find_map(|marker| {
+                let name = fs::read_to_string(&marker).ok()?.trim().to_string();
+                (!name.is_empty()).then_some((name, marker))
+            })
+    })
+}
+
 // Note: Struct and field names retained for backward compatibility with vaults.toml
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct VaultsConfig {
@@ -35,20 +74,39 @@ struct VaultsConfig {
     vaults: Vec<DeckMetadata>,
 }
 
+/// Result of [`DeckRegistry::migrate_legacy_deck`].
+#[derive(Debug, PartialEq)]
+pub enum LegacyMigration {
+    NoLegacyDeck,
+    AlreadyRegistered(String),
+    Migrated(String),
+}
+
 pub struct DeckRegistry {
     config_dir: PathBuf,
+    /// Base for relative deck paths in vaults.toml (the `vaults_dir` setting)
+    decks_dir: PathBuf,
 }
 
 impl DeckRegistry {
     pub fn new(config_dir: PathBuf) -> Self {
-        Self { config_dir }
+        Self {
+            decks_dir: config_dir.clone(),
+            config_dir,
+        }
     }
 
     pub fn load(config_dir: &Path) -> Result<Self> {
-        let registry = Self::new(config_dir.to_path_buf());
+        let mut registry = Self::new(config_dir.to_path_buf());
+        registry.decks_dir = Config::decks_dir_for(config_dir);
 
         if !registry.registry_path().exists() {
-            registry.migrate_legacy_deck()?;
+            if let LegacyMigration::Migrated(name) = registry.migrate_legacy_deck()? {
+                eprintln!(
+                    "✓ Registered legacy vault.enc as deck '{}' (see 'hc migrate')",
+                    name
+                );
+            }
         }
 
         Ok(registry)
@@ -119,6 +177,58 @@ impl DeckRegistry {
         Ok(())
     }
 
+    fn absolute(&self, mut deck: DeckMetadata) -> DeckMetadata {
+        if deck.path.is_relative() {
+            deck.path = self.decks_dir.join(&deck.path);
+        }
+        deck
+    }
+
+    /// Rename a deck, moving its file too when it is named `<name>.enc`.
+    pub fn rename_deck(&self, old_name: &str, new_name: &str) -> Result<DeckMetadata> {
+        let mut config = self.load_config()?;
+
+        if config.vaults.iter().any(|v| v.name == new_name) {
+            anyhow::bail!("Deck '{}' already exists", new_name);
+        }
+
+        let deck = config
+            .vaults
+            .iter_mut()
+            .find(|v| v.name == old_name)
+            .ok_or_else(|| anyhow::anyhow!("Deck '{}' not found", old_name))?;
+
+        let default_name = format!("{}.enc", old_name);
+        if deck
+            .path
+            .file_name()
+            .is_some_and(|name| *name == *default_name)
+        {
+            let stored_path = deck.path.with_file_name(format!("{}.enc", new_name));
+            let old_path = self.absolute(deck.clone()).path;
+            let new_path = old_path.with_file_name(format!("{}.enc", new_name));
+            if new_path.exists() {
+                anyhow::bail!("Deck file already exists at: {}", new_path.display());
+            }
+            if old_path.exists() {
+                fs::rename(&old_path, &new_path).with_context(|| {
+                    format!("Failed to rename deck file: {}", old_path.display())
+                })?;
+            }
+            deck.path = stored_path;
+        }
+
+        deck.name = new_name.to_string();
+        let metadata = self.absolute(deck.clone());
+
+        if config.active_vault == old_name {
+            config.active_vault = new_name.to_string();
+        }
+
+        self.save_config(&config)?;
+        Ok(metadata)
+    }
+
     pub fn set_active(&self, name: &str) -> Result<()> {
         let mut config = self.load_config()?;
 
@@ -138,6 +248,7 @@ impl DeckRegistry {
             .vaults
             .into_iter()
             .find(|v| v.name == name)
+            .map(|deck| self.absolute(deck))
             .ok_or_else(|| anyhow::anyhow!("Deck '{}' not found", name))
     }
 
@@ -151,9 +262,32 @@ impl DeckRegistry {
         self.get_deck(&config.active_vault)
     }
 
+    /// Resolve the deck a command acts on: the `--deck` override if given,
+    /// then a `.holecard-deck` marker in the current directory or an
+    /// ancestor, otherwise the active deck.
+    pub fn resolve_deck(&self, deck_name: Option<&str>) -> Result<DeckMetadata> {
+        if let Some(name) = deck_name {
+            return self.get_deck(name);
+        }
+
+        let local = std::env::current_dir()
+            .ok()
+            .and_then(|cwd| find_local_deck(&cwd));
+        match local {
+            Some((name, marker)) => self.get_deck(&name).with_context(|| {
+                format!("Deck selected by {} is not registered", marker.display())
+            }),
+            None => self.get_active_deck(),
+        }
+    }
+
     pub fn list_decks(&self) -> Result<Vec<DeckMetadata>> {
         let config = self.load_config()?;
-        let mut decks = config.vaults;
+        let mut decks: Vec<DeckMetadata> = config
+            .vaults
+            .into_iter()
+            .map(|deck| self.absolute(deck))
+            .collect();
         decks.sort_by_key(|d| std::cmp::Reverse(d.last_accessed));
         Ok(decks)
     }
@@ -170,22 +304,156 @@ impl DeckRegistry {
         }
     }
 
-    fn migrate_legacy_deck(&self) -> Result<()> {
+    pub fn set_overrides(&self, name: &str, overrides: DeckConfigOverrides) -> Result<()> {
+        let mut config = self.load_config()?;
+
+        let deck = config
+            .vaults
+            .iter_mut()
+            .find(|v| v.name == name)
+            .ok_or_else(|| anyhow::anyhow!("Deck '{}' not found", name))?;
+        deck.overrides = overrides;
+
+        self.save_config(&config)
+    }
+
+    /// Register the pre-multi-deck `vault.enc` as a deck. Safe to re-run:
+    /// an already registered legacy file is reported, not added twice.
+    pub fn migrate_legacy_deck(&self) -> Result<LegacyMigration> {
         let legacy_deck_path = self.config_dir.join("vault.enc");
+        if !legacy_deck_path.exists() {
+            return Ok(LegacyMigration::NoLegacyDeck);
+        }
 
-        if legacy_deck_path.exists() {
-            println!("🔄 Migrating existing deck to 'default' deck...");
+        let mut config = self.load_config()?;
+        if let Some(deck) = config
+            .vaults
+            .iter()
+            .find(|v| self.absolute((*v).clone()).path == legacy_deck_path)
+        {
+            return Ok(LegacyMigration::AlreadyRegistered(deck.name.clone()));
+        }
 
-            let metadata = DeckMetadata::new("default".to_string(), legacy_deck_path);
-            let config = VaultsConfig {
-                active_vault: "default".to_string(),
-                vaults: vec![metadata],
-            };
+        let name = ["default", "legacy"]
+            .into_iter()
+            .find(|name| !config.vaults.iter().any(|v| v.name == *name))
+            .context("Decks named 'default' and 'legacy' already exist")?
+            .to_string();
 
-            self.save_config(&config)?;
-            println!("✓ Migration complete. Your deck is now named 'default'.");
+        config
+            .vaults
+            .push(DeckMetadata::new(name.clone(), legacy_deck_path));
+        if config.active_vault.is_empty() {
+            config.active_vault = name.clone();
         }
+        self.save_config(&config)?;
 
-        Ok(())
+        Ok(LegacyMigration::Migrated(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_local_deck_walks_ancestors() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("project/src/module");
+        fs::create_dir_all(&nested).unwrap();
+        assert!(find_local_deck(&nested).is_none());
+
+        fs::write(dir.path().join("project").join(LOCAL_DECK_FILE), "work\n").unwrap();
+        let (name, marker) = find_local_deck(&nested).unwrap();
+        assert_eq!(name, "work");
+        assert_eq!(marker, dir.path().join("project").join(LOCAL_DECK_FILE));
+
+        fs::write(nested.join(LEGACY_LOCAL_DECK_FILE), "legacy").unwrap();
+        assert_eq!(find_local_deck(&nested).unwrap().0, "legacy");
+    }
+
+    #[test]
+    fn test_set_overrides_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = DeckRegistry::new(dir.path().to_path_buf());
+        registry
+            .create_deck("work", dir.path().join("work.enc"))
+            .unwrap();
+
+        let overrides = DeckConfigOverrides {
+            session_timeout_minutes: Some(15),
+            enable_biometric: None,
+        };
+        registry.set_overrides("work", overrides.clone()).unwrap();
+        assert_eq!(registry.get_deck("work").unwrap().overrides, overrides);
+        assert!(registry
+            .set_overrides("missing", DeckConfigOverrides::default())
+            .is_err());
+    }
+
+    #[test]
+    fn test_rename_deck_moves_file_and_active() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = DeckRegistry::new(dir.path().to_path_buf());
+
+        let old_path = dir.path().join("work.enc");
+        fs::write(&old_path, b"data").unwrap();
+        registry.create_deck("work", old_path.clone()).unwrap();
+        registry
+            .create_deck("other", dir.path().join("other.enc"))
+            .unwrap();
+
+        assert!(registry.rename_deck("work", "other").is_err());
+
+        let renamed = registry.rename_deck("work", "job").unwrap();
+        assert_eq!(renamed.path, dir.path().join("job.enc"));
+        assert!(renamed.path.exists());
+        assert!(!old_path.exists());
+        assert_eq!(registry.get_active_deck().unwrap().name, "job");
+        assert!(registry.get_deck("work").is_err());
+    }
+
+    #[test]
+    fn test_relative_deck_paths_resolve_against_decks_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = DeckRegistry::new(dir.path().to_path_buf());
+        registry
+            .create_deck("synced", PathBuf::from("synced.enc"))
+            .unwrap();
+        fs::write(dir.path().join("synced.enc"), b"data").unwrap();
+
+        assert_eq!(
+            registry.get_deck("synced").unwrap().path,
+            dir.path().join("synced.enc")
+        );
+
+        let renamed = registry.rename_deck("synced", "cloud").unwrap();
+        assert_eq!(renamed.path, dir.path().join("cloud.enc"));
+        assert!(dir.path().join("cloud.enc").exists());
+        assert!(fs::read_to_string(dir.path().join("vaults.toml"))
+            .unwrap()
+            .contains("path = \"cloud.enc\""));
+    }
+
+    #[test]
+    fn test_migrate_legacy_deck_is_idempotent() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = DeckRegistry::new(dir.path().to_path_buf());
+        assert_eq!(
+            registry.migrate_legacy_deck().unwrap(),
+            LegacyMigration::NoLegacyDeck
+        );
+
+        fs::write(dir.path().join("vault.enc"), b"legacy").unwrap();
+        assert_eq!(
+            registry.migrate_legacy_deck().unwrap(),
+            LegacyMigration::Migrated("default".to_string())
+        );
+        assert_eq!(registry.get_active_deck().unwrap().name, "default");
+        assert_eq!(
+            registry.migrate_legacy_deck().unwrap(),
+            LegacyMigration::AlreadyRegistered("default".to_string())
+        );
+        assert_eq!(registry.list_decks().unwrap().len(), 1);
     }
 }
@@ -3,6 +3,10 @@ use keyring::Entry;
 use std::fs;
 use std::path::PathBuf;
 
+use super::write_private_file;
+#[cfg(not(target_os = "macos"))]
+use crate::cli::output::status;
+
 const SERVICE_NAME: &str = "hc";
 const USERNAME: &str = "secret_key";
 #[cfg(not(target_os = "macos"))]
@@ -29,10 +33,15 @@ impl KeyringManager {
 
     pub fn load_secret_key(&self) -> Result<String> {
         if let Some(key) = self.try_load_from_keyring() {
+            log::debug!("Secret key loaded from the OS keyring");
             return Ok(key);
         }
 
         if self.fallback_path.exists() {
+            log::debug!(
+                "Secret key not in the OS keyring; reading {}",
+                self.fallback_path.display()
+            );
             fs::read_to_string(&self.fallback_path)
                 .map(|s| s.trim().to_string())
                 .context("Failed to read secret key from fallback file")
@@ -63,10 +72,22 @@ impl KeyringManager {
         #[cfg(not(target_os = "macos"))]
         {
             let username = format!("{}-{}", MASTER_PASSWORD_PREFIX, deck_name);
-            Entry::new(SERVICE_NAME, &username)
-                .map_err(|e| anyhow::anyhow!("Failed to access keyring: {}", e))?
-                .set_password(master_password)
-                .context("Failed to save master password to OS keyring")
+            let saved = Entry::new(SERVICE_NAME, &username)
+                .and_then(|entry| entry.set_password(master_password));
+            match saved {
+                Ok(()) => Ok(()),
+                // No usable keyring (headless Linux, locked Credential Manager).
+                // A file would sit next to the secret-key fallback and put both
+                // unlock factors on disk, so skip caching and keep prompting.
+                Err(e) => {
+                    log::debug!("OS keyring unavailable for the master password: {}", e);
+                    status!(
+                        "⚠ No OS keyring available; the master password for '{}' will not be cached",
+                        deck_name
+                    );
+                    Ok(())
+                }
+            }
         }
     }
 
@@ -78,17 +99,13 @@ impl KeyringManager {
         #[cfg(not(target_os = "macos"))]
         {
             let username = format!("{}-{}", MASTER_PASSWORD_PREFIX, deck_name);
-            match Entry::new(SERVICE_NAME, &username) {
-                Ok(entry) => match entry.get_password() {
-                    Ok(pwd) => Ok(Some(pwd.trim().to_string())),
-                    Err(_) => Ok(None),
-                },
-                Err(_) => Ok(None),
-            }
+            // Passwords are returned verbatim: surrounding spaces are significant.
+            Ok(Entry::new(SERVICE_NAME, &username)
+                .and_then(|e| e.get_password())
+                .ok())
         }
     }
 
-    #[allow(dead_code)]
     pub fn delete_master_password(&self, deck_name: &str) -> Result<()> {
         #[cfg(target_os = "macos")]
         {
@@ -104,6 +121,11 @@ impl KeyringManager {
         }
     }
 
+    /// True when the secret key lives in the fallback file rather than the OS keyring
+    pub fn uses_fallback_file(&self) -> bool {
+        self.try_load_from_keyring().is_none() && self.fallback_path.exists()
+    }
+
     fn try_load_from_keyring(&self) -> Option<String> {
         Entry::new(SERVICE_NAME, USERNAME)
             .ok()?
@@ -113,15 +135,31 @@ impl KeyringManager {
     }
 
     fn save_secret_key_to_file(&self, secret_key: &str) -> Result<()> {
-        fs::write(&self.fallback_path, secret_key)
-            .context("Failed to save secret key to fallback file")?;
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let mut perms = fs::metadata(&self.fallback_path)?.permissions();
-            perms.set_mode(0o600);
-            fs::set_permissions(&self.fallback_path, perms)?;
-        }
-        Ok(())
+        write_private_file(&self.fallback_path, secret_key)
+            .context("Failed to save secret key to fallback file")
+    }
+}
+
+#[cfg(all(test, windows))]
+mod tests {
+    use super::*;
+
+    /// Exercises the real Windows Credential Manager backend.
+    #[test]
+    fn test_windows_master_password_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let keyring = KeyringManager::new(dir.path().to_path_buf());
+        let deck = format!("hc-test-{}", std::process::id());
+
+        keyring
+            .save_master_password(&deck, " correct horse ")
+            .unwrap();
+        assert_eq!(
+            keyring.load_master_password(&deck).unwrap().as_deref(),
+            Some(" correct horse ")
+        );
+
+        keyring.delete_master_password(&deck).unwrap();
+        assert_eq!(keyring.load_master_password(&deck).unwrap(), None);
     }
 }
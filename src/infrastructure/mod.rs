@@ -1,9 +1,11 @@
 pub mod biometric;
 pub mod crypto_impl;
+pub mod deck_lock;
 pub mod deck_registry;
 #[cfg(target_os = "macos")]
 pub mod keychain_macos;
 pub mod keyring;
+pub mod private_file;
 pub mod provider_storage;
 pub mod session;
 pub mod ssh_agent;
@@ -12,10 +14,17 @@ pub mod storage;
 pub mod swift_runner;
 
 pub use biometric::{get_biometric_auth, require_biometric_auth};
-pub use crypto_impl::{decrypt_for_import, encrypt_for_export, CryptoServiceImpl};
-pub use deck_registry::DeckRegistry;
+pub use crypto_impl::{
+    decrypt_for_import, encrypt_for_export, time_kdf, CryptoServiceImpl, KDF_ITERATIONS,
+    KDF_MEMORY_KIB,
+};
+pub use deck_lock::DeckLock;
+pub use deck_registry::{
+    find_local_deck, DeckConfigOverrides, DeckRegistry, LegacyMigration, LOCAL_DECK_FILE,
+};
 pub use keyring::KeyringManager;
+pub use private_file::write_private_file;
 pub use provider_storage::{create_provider, ProviderStorage};
 pub use session::{SessionData, SessionManager};
-pub use ssh_agent::SshAgent;
+pub use ssh_agent::{write_private_key_file, SshAgent};
 pub use storage::DeckStorage;
@@ -1,7 +1,7 @@
 use crate::domain::{
     error::ProviderError,
     provider::{Provider, ProviderConfig},
-    providers::{cloudflare::CloudflareProvider, github::GitHubProvider},
+    providers::{cloudflare::CloudflareProvider, github::GitHubProvider, vercel::VercelProvider},
     CryptoError, CryptoService,
 };
 use anyhow::{Context, Result};
@@ -96,30 +96,37 @@ impl<C: CryptoService> ProviderStorage<C> {
 pub fn create_provider(config: &ProviderConfig) -> Result<Box<dyn Provider>> {
     match config.provider_type.as_str() {
         "github" => {
-            let repo = config.credentials.get("repo").ok_or_else(|| {
-                ProviderError::ConfigError("Missing 'repo' credential".to_string())
-            })?;
-            let token = config.credentials.get("token").ok_or_else(|| {
-                ProviderError::ConfigError("Missing 'token' credential".to_string())
-            })?;
-
-            Ok(Box::new(GitHubProvider::new(repo.clone(), token.clone())))
+            let repo = resolve_credential(config, "repo")?;
+            let token = resolve_credential(config, "token")?;
+
+            Ok(Box::new(GitHubProvider::new(
+                config.provider_id.clone(),
+                repo,
+                token,
+            )))
         }
         "cloudflare" => {
-            let account_id = config.credentials.get("account_id").ok_or_else(|| {
-                ProviderError::ConfigError("Missing 'account_id' credential".to_string())
-            })?;
-            let worker_name = config.credentials.get("worker_name").ok_or_else(|| {
-                ProviderError::ConfigError("Missing 'worker_name' credential".to_string())
-            })?;
-            let token = config.credentials.get("token").ok_or_else(|| {
-                ProviderError::ConfigError("Missing 'token' credential".to_string())
-            })?;
+            let account_id = resolve_credential(config, "account_id")?;
+            let worker_name = resolve_credential(config, "worker_name")?;
+            let token = resolve_credential(config, "token")?;
 
             Ok(Box::new(CloudflareProvider::new(
-                account_id.clone(),
-                worker_name.clone(),
-                token.clone(),
+                config.provider_id.clone(),
+                account_id,
+                worker_name,
+                token,
+            )))
+        }
+        "vercel" => {
+            let project_id = resolve_credential(config, "project_id")?;
+            let token = resolve_credential(config, "token")?;
+            let target = resolve_credential(config, "target")?;
+
+            Ok(Box::new(VercelProvider::new(
+                config.provider_id.clone(),
+                project_id,
+                token,
+                target,
             )))
         }
         _ => Err(ProviderError::ConfigError(format!(
@@ -129,3 +136,60 @@ pub fn create_provider(config: &ProviderConfig) -> Result<Box<dyn Provider>> {
         .into()),
     }
 }
+
+/// Prefix marking a credential that is read from the environment at use time
+const ENV_PREFIX: &str = "env:";
+
+/// Look up a credential, resolving `env:VAR` values from the environment
+fn resolve_credential(config: &ProviderConfig, key: &str) -> Result<String> {
+    let value = config
+        .credentials
+        .get(key)
+        .ok_or_else(|| ProviderError::ConfigError(format!("Missing '{}' credential", key)))?;
+
+    match value.strip_prefix(ENV_PREFIX) {
+        Some(var) => std::env::var(var).map_err(|_| {
+            ProviderError::ConfigError(format!(
+                "Credential '{}' refers to environment variable '{}', which is not set",
+                key, var
+            ))
+            .into()
+        }),
+        None => Ok(value.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_token(token: &str) -> ProviderConfig {
+        let mut credentials = HashMap::new();
+        credentials.insert("token".to_string(), token.to_string());
+        ProviderConfig {
+            provider_type: "github".to_string(),
+            provider_id: "test".to_string(),
+            credentials,
+        }
+    }
+
+    #[test]
+    fn test_resolve_literal_credential() {
+        let config = config_with_token("ghp_literal");
+        assert_eq!(resolve_credential(&config, "token").unwrap(), "ghp_literal");
+        assert!(resolve_credential(&config, "repo").is_err());
+    }
+
+    #[test]
+    fn test_resolve_env_credential() {
+        std::env::set_var("HC_TEST_PROVIDER_TOKEN", "ghp_from_env");
+        let config = config_with_token("env:HC_TEST_PROVIDER_TOKEN");
+        assert_eq!(
+            resolve_credential(&config, "token").unwrap(),
+            "ghp_from_env"
+        );
+
+        let missing = config_with_token("env:HC_TEST_PROVIDER_TOKEN_UNSET");
+        assert!(resolve_credential(&missing, "token").is_err());
+    }
+}
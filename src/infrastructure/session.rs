@@ -21,13 +21,13 @@ struct SessionMetadata {
 pub struct SessionData {
     pub derived_key: [u8; 32],
     pub salt: [u8; 16],
-    #[allow(dead_code)]
     pub hand_names: Vec<String>,
 }
 
 pub struct SessionManager {
     service_name: String,
     session_file: PathBuf,
+    names_cache_file: PathBuf,
     timeout_minutes: u64,
 }
 
@@ -36,6 +36,7 @@ impl SessionManager {
         Self {
             service_name: format!("{}-{}", SERVICE_NAME_PREFIX, deck_name),
             session_file: config_dir.join(format!("session_{}.json", deck_name)),
+            names_cache_file: config_dir.join(format!("names_{}.json", deck_name)),
             timeout_minutes,
         }
     }
@@ -61,6 +62,10 @@ impl SessionManager {
             }
         }
 
+        // Hand names aren't secret; keep a copy outside the session so tab
+        // completion still works after the deck is locked.
+        let _ = fs::write(&self.names_cache_file, serde_json::to_string(&card_names)?);
+
         let metadata = SessionMetadata {
             created_at: now,
             last_accessed: now,
@@ -139,19 +144,52 @@ impl SessionManager {
         Ok(())
     }
 
+    /// Clear the session if it has been idle past the timeout. Reads only the
+    /// session metadata, so the derived key is never loaded and the access
+    /// time is left untouched. Returns true if a session was cleared.
+    pub fn expire_if_idle(&self) -> Result<bool> {
+        if !self.session_file.exists() {
+            return Ok(false);
+        }
+
+        let content = fs::read_to_string(&self.session_file)?;
+        let metadata: SessionMetadata = serde_json::from_str(&content)?;
+        let elapsed_minutes = current_timestamp().saturating_sub(metadata.last_accessed) / 60;
+
+        if elapsed_minutes < self.timeout_minutes {
+            return Ok(false);
+        }
+
+        self.clear_session()?;
+        Ok(true)
+    }
+
     pub fn is_active(&self) -> bool {
         self.load_session().ok().flatten().is_some()
     }
 
+    /// Hand names from the active session, falling back to the names cache
+    /// left behind by the last unlock.
     pub fn load_card_names(&self) -> Result<Vec<String>> {
-        if !self.session_file.exists() {
+        if self.session_file.exists() {
+            let content = fs::read_to_string(&self.session_file)?;
+            let metadata: SessionMetadata = serde_json::from_str(&content)?;
+            return Ok(metadata.card_names);
+        }
+
+        if !self.names_cache_file.exists() {
             return Ok(Vec::new());
         }
 
-        let content = fs::read_to_string(&self.session_file)?;
-        let metadata: SessionMetadata = serde_json::from_str(&content)?;
+        let content = fs::read_to_string(&self.names_cache_file)?;
+        Ok(serde_json::from_str(&content)?)
+    }
 
-        Ok(metadata.card_names)
+    pub fn clear_names_cache(&self) -> Result<()> {
+        if self.names_cache_file.exists() {
+            fs::remove_file(&self.names_cache_file)?;
+        }
+        Ok(())
     }
 
     fn touch_session(&self) -> Result<()> {
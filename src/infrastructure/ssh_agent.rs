@@ -7,9 +7,10 @@ pub struct SshAgent;
 
 impl SshAgent {
     pub fn connect() -> Result<Self> {
-        if std::env::var("SSH_AUTH_SOCK").is_err() {
+        let Ok(socket) = std::env::var("SSH_AUTH_SOCK") else {
             bail!("ssh-agent is not running. Start it with 'eval $(ssh-agent -s)'");
-        }
+        };
+        log::debug!("Using ssh-agent at {}", socket);
 
         let output = run_ssh_add(&["-l"])?;
 
@@ -26,18 +27,7 @@ impl SshAgent {
         passphrase: Option<&str>,
         lifetime: Option<u32>,
     ) -> Result<()> {
-        let mut temp_file = NamedTempFile::new().context("Failed to create temporary file")?;
-
-        temp_file
-            .write_all(private_key.as_bytes())
-            .context("Failed to write private key to temp file")?;
-        temp_file.flush()?;
-
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            std::fs::set_permissions(temp_file.path(), std::fs::Permissions::from_mode(0o600))?;
-        }
+        let temp_file = write_private_key_file(private_key)?;
 
         let mut cmd = Command::new("ssh-add");
 
@@ -46,6 +36,14 @@ impl SshAgent {
         }
 
         cmd.arg(temp_file.path());
+        log::debug!(
+            "Running ssh-add {} (passphrase on stdin: {})",
+            cmd.get_args()
+                .map(|arg| arg.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" "),
+            passphrase.is_some()
+        );
 
         let output = if let Some(pass) = passphrase {
             cmd.stdin(Stdio::piped());
@@ -67,9 +65,68 @@ impl SshAgent {
         check_ssh_add_output(&output, "remove")?;
         Ok(())
     }
+
+    /// Remove an identity given the contents of its public key
+    pub fn remove_public_key(&self, public_key: &str) -> Result<()> {
+        let mut temp_file = NamedTempFile::new().context("Failed to create temporary file")?;
+        writeln!(temp_file, "{}", public_key.trim())
+            .context("Failed to write public key to temp file")?;
+        temp_file.flush()?;
+
+        let path = temp_file.path().to_string_lossy().to_string();
+        let output = run_ssh_add(&["-d", &path])?;
+        check_ssh_add_output(&output, "remove")?;
+        Ok(())
+    }
+
+    /// Fingerprint lines of the identities currently held by the agent
+    pub fn list_identities(&self) -> Result<Vec<String>> {
+        let output = run_ssh_add(&["-l"])?;
+
+        // ssh-add exits with 1 when the agent has no identities
+        if output.status.code() == Some(1) {
+            return Ok(Vec::new());
+        }
+        check_ssh_add_output(&output, "list")?;
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.to_string())
+            .collect())
+    }
+
+    pub fn remove_all_identities(&self) -> Result<()> {
+        let output = run_ssh_add(&["-D"])?;
+        check_ssh_add_output(&output, "remove")?;
+        Ok(())
+    }
+}
+
+/// Write a private key to a mode-600 temporary file, deleted when dropped
+pub fn write_private_key_file(private_key: &str) -> Result<NamedTempFile> {
+    let mut temp_file = NamedTempFile::new().context("Failed to create temporary file")?;
+
+    temp_file
+        .write_all(private_key.as_bytes())
+        .context("Failed to write private key to temp file")?;
+    // OpenSSH refuses keys without a trailing newline
+    if !private_key.ends_with('\n') {
+        temp_file.write_all(b"\n")?;
+    }
+    temp_file.flush()?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(temp_file.path(), std::fs::Permissions::from_mode(0o600))?;
+    }
+
+    Ok(temp_file)
 }
 
 fn run_ssh_add(args: &[&str]) -> Result<Output> {
+    log::debug!("Running ssh-add {}", args.join(" "));
     Command::new("ssh-add")
         .args(args)
         .output()
@@ -77,6 +134,7 @@ fn run_ssh_add(args: &[&str]) -> Result<Output> {
 }
 
 fn check_ssh_add_output(output: &Output, action: &str) -> Result<()> {
+    log::debug!("ssh-add {} exited with {}", action, output.status);
     if !output.status.success() {
         bail!(
             "Failed to {} SSH key: {}",
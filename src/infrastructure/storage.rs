@@ -30,7 +30,15 @@ impl<C: CryptoService> DeckStorage<C> {
         }
 
         let ciphertext = &encrypted_data[SALT_LEN..];
-        let decrypted_data = self.crypto.decrypt_with_key(ciphertext, derived_key)?;
+        let decrypted_data = self
+            .crypto
+            .decrypt_with_key(ciphertext, derived_key)
+            .map_err(|e| match e {
+                // AES-GCM cannot tell a wrong key from tampering; a wrong key is
+                // by far the common case, so report it as such.
+                CryptoError::DecryptionFailed => CryptoError::IncorrectPassword,
+                other => other,
+            })?;
 
         let deck: Deck = serde_json::from_slice(&decrypted_data)
             .map_err(|e| CryptoError::InvalidData(format!("Failed to deserialize deck: {}", e)))?;
@@ -116,3 +124,58 @@ impl<C: CryptoService> DeckStorage<C> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::CryptoServiceImpl;
+
+    #[test]
+    fn test_load_with_wrong_key_reports_incorrect_password() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("deck.enc");
+        let storage = DeckStorage::new(CryptoServiceImpl::new());
+
+        let (key, salt) = storage
+            .derive_key_from_deck(&path, "correct horse battery", "secret")
+            .unwrap();
+        storage
+            .save_with_cached_key(&Deck::new(), &path, &key, &salt)
+            .unwrap();
+
+        assert!(storage.load_with_cached_key(&path, &key).is_ok());
+
+        let (wrong_key, _) = storage
+            .derive_key_from_deck(&path, "wrong password!!", "secret")
+            .unwrap();
+        let result = storage.load_with_cached_key(&path, &wrong_key);
+        assert!(matches!(result, Err(CryptoError::IncorrectPassword)));
+    }
+
+    #[test]
+    fn test_each_save_uses_a_fresh_nonce() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("deck.enc");
+        let storage = DeckStorage::new(CryptoServiceImpl::new());
+
+        let (key, salt) = storage
+            .derive_key_from_deck(&path, "correct horse battery", "secret")
+            .unwrap();
+
+        storage
+            .save_with_cached_key(&Deck::new(), &path, &key, &salt)
+            .unwrap();
+        let first = fs::read(&path).unwrap();
+        storage
+            .save_with_cached_key(&Deck::new(), &path, &key, &salt)
+            .unwrap();
+        let second = fs::read(&path).unwrap();
+
+        // Same salt header, different nonce + ciphertext
+        assert_eq!(first[..SALT_LEN], second[..SALT_LEN]);
+        assert_ne!(
+            first[SALT_LEN..SALT_LEN + 12],
+            second[SALT_LEN..SALT_LEN + 12]
+        );
+    }
+}
@@ -10,13 +10,30 @@ use anyhow::Result;
 use clap::Parser;
 use cli::commands::{CardCommands, Cli, Commands, HandCommands};
 use config::get_config_dir;
+use domain::HcError;
 use infrastructure::KeyringManager;
 
-fn main() -> Result<()> {
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("Error: {:?}", e);
+        std::process::exit(HcError::exit_code_for(&e));
+    }
+}
+
+fn run() -> Result<()> {
     let cli = Cli::parse();
+    cli::output::set_quiet(cli.quiet);
+    cli::output::init_color(cli.no_color);
+    cli::output::init_logging(cli.verbose);
+    if cli.dry_run && !cli.command.supports_dry_run() {
+        anyhow::bail!("--dry-run is not supported for this command");
+    }
+    deck_context::set_dry_run(cli.dry_run);
     let config_dir = get_config_dir()?;
+    log::debug!("Config directory: {}", config_dir.display());
     let keyring = KeyringManager::new(config_dir.clone());
     let deck_name = cli.deck.as_deref();
+    let assume_yes = cli.yes;
 
     match cli.command {
         Commands::Init => handlers::deck::handle_init(&keyring, &config_dir),
@@ -25,6 +42,9 @@ fn main() -> Result<()> {
                 name,
                 field,
                 file,
+                field_stdin,
+                from_clipboard,
+                json,
                 generate,
                 gen_length,
                 gen_memorable,
@@ -38,6 +58,9 @@ fn main() -> Result<()> {
                 name,
                 field,
                 file,
+                field_stdin,
+                from_clipboard,
+                json,
                 generate,
                 gen_length,
                 gen_memorable,
@@ -54,78 +77,243 @@ fn main() -> Result<()> {
             HandCommands::Get {
                 name,
                 clip,
+                clip_all,
                 totp,
                 show,
+                field,
+                history,
+                notes,
+                reveal_prefix,
+                qr,
+                out,
+                no_newline,
             } => handlers::deck::handle_get(
-                &name,
+                name.as_deref(),
                 clip,
+                clip_all,
                 totp,
                 show,
+                field.as_deref(),
+                history.as_deref(),
+                notes,
+                reveal_prefix,
+                qr,
+                out,
+                no_newline,
+                deck_name,
+                &keyring,
+                &config_dir,
+            ),
+            HandCommands::List {
+                show,
+                sort,
+                reverse,
+            } => handlers::deck::handle_list(
+                show,
+                sort,
+                reverse,
+                assume_yes,
                 deck_name,
                 &keyring,
                 &config_dir,
             ),
-            HandCommands::List => handlers::deck::handle_list(deck_name, &keyring, &config_dir),
             HandCommands::Edit {
                 name,
                 interactive,
                 field,
                 file,
+                from_clipboard,
                 rm_card,
+                generate,
+                gen_length,
+                gen_memorable,
+                gen_words,
+                gen_no_uppercase,
+                gen_no_lowercase,
+                gen_no_digits,
+                gen_no_symbols,
+                clip,
+                track_values,
+                no_track_values,
             } => {
                 if interactive {
-                    handlers::deck::handle_edit_interactive(&name, deck_name, &keyring, &config_dir)
+                    handlers::deck::handle_edit_interactive(
+                        name.as_deref(),
+                        deck_name,
+                        &keyring,
+                        &config_dir,
+                    )
                 } else {
                     handlers::deck::handle_edit(
-                        &name,
+                        name.as_deref(),
                         field,
                         file,
+                        from_clipboard,
                         rm_card,
+                        generate,
+                        gen_length,
+                        gen_memorable,
+                        gen_words,
+                        gen_no_uppercase,
+                        gen_no_lowercase,
+                        gen_no_digits,
+                        gen_no_symbols,
+                        clip,
+                        track_values,
+                        no_track_values,
                         deck_name,
                         &keyring,
                         &config_dir,
                     )
                 }
             }
-            HandCommands::Remove { name } => {
-                handlers::deck::handle_rm(&name, deck_name, &keyring, &config_dir)
+            HandCommands::Remove { name, pattern } => match pattern {
+                Some(pattern) => handlers::deck::handle_rm_matching(
+                    &pattern,
+                    assume_yes,
+                    deck_name,
+                    &keyring,
+                    &config_dir,
+                ),
+                None => {
+                    handlers::deck::handle_rm(name.as_deref(), deck_name, &keyring, &config_dir)
+                }
+            },
+            HandCommands::Clip { name, card } => handlers::deck::handle_clip(
+                &name,
+                card.as_deref(),
+                deck_name,
+                &keyring,
+                &config_dir,
+            ),
+            HandCommands::Open { name, clip } => {
+                handlers::deck::handle_open(&name, clip, deck_name, &keyring, &config_dir)
+            }
+            HandCommands::Login { name } => {
+                handlers::deck::handle_login(&name, deck_name, &keyring, &config_dir)
+            }
+            HandCommands::Recovery { name } => {
+                handlers::deck::handle_recovery(&name, deck_name, &keyring, &config_dir)
+            }
+            HandCommands::Find {
+                field: (key, value),
+                contains,
+            } => handlers::deck::handle_find(
+                &key,
+                &value,
+                contains,
+                assume_yes,
+                deck_name,
+                &keyring,
+                &config_dir,
+            ),
+            HandCommands::History { name } => {
+                handlers::deck::handle_history(&name, deck_name, &keyring, &config_dir)
+            }
+            HandCommands::Duplicate { name, new_name } => {
+                handlers::deck::handle_duplicate(&name, new_name, deck_name, &keyring, &config_dir)
             }
             HandCommands::Card { subcommand } => match subcommand {
                 CardCommands::Rm { hand, card } => {
                     handlers::deck::handle_card_rm(&hand, &card, deck_name, &keyring, &config_dir)
                 }
+                CardCommands::Mv {
+                    hand,
+                    old_key,
+                    new_key,
+                } => handlers::deck::handle_card_mv(
+                    &hand,
+                    &old_key,
+                    &new_key,
+                    deck_name,
+                    &keyring,
+                    &config_dir,
+                ),
+                CardCommands::RenameField {
+                    old_key,
+                    new_key,
+                    hand: Some(hand),
+                    ..
+                } => handlers::deck::handle_card_mv(
+                    &hand,
+                    &old_key,
+                    &new_key,
+                    deck_name,
+                    &keyring,
+                    &config_dir,
+                ),
+                CardCommands::RenameField {
+                    old_key, new_key, ..
+                } => handlers::deck::handle_card_mv_all(
+                    &old_key,
+                    &new_key,
+                    assume_yes,
+                    deck_name,
+                    &keyring,
+                    &config_dir,
+                ),
             },
         },
-        Commands::Config { subcommand } => handlers::config::handle_config(subcommand, &config_dir),
-        Commands::Read { uri } => {
-            handlers::read::handle_read(&uri, deck_name, &keyring, &config_dir)
+        Commands::Config { subcommand } => {
+            handlers::config::handle_config(subcommand, assume_yes, deck_name, &config_dir)
         }
+        Commands::Read {
+            uri,
+            out,
+            no_newline,
+        } => handlers::read::handle_read(
+            &uri,
+            out.as_deref(),
+            no_newline,
+            deck_name,
+            &keyring,
+            &config_dir,
+        ),
         Commands::Inject {
             template,
             input,
             output,
+            in_place,
+            backup,
+            no_newline,
         } => handlers::inject::handle_inject(
             template,
             input,
             output,
+            in_place,
+            backup,
+            no_newline,
+            deck_name,
+            &keyring,
+            &config_dir,
+        ),
+        Commands::Run {
+            env,
+            env_file,
+            namespace,
+            command,
+        } => handlers::run::handle_run(
+            env,
+            env_file.as_deref(),
+            namespace,
+            &command,
             deck_name,
             &keyring,
             &config_dir,
         ),
-        Commands::Run { env, command } => {
-            handlers::run::handle_run(env, &command, deck_name, &keyring, &config_dir)
-        }
         Commands::Deal {
             hand,
             deck,
             no_uppercase,
             prefix,
             env,
+            env_file,
             command,
         } => {
             let options = handlers::deal::DealOptions {
                 uppercase: !no_uppercase,
                 prefix,
+                env_file,
                 additional_env: env,
             };
             handlers::deal::handle_deal(
@@ -137,20 +325,65 @@ fn main() -> Result<()> {
                 &config_dir,
             )
         }
-        Commands::Lock => handlers::session::handle_lock(&config_dir),
+        Commands::Lock { idle, interval } => {
+            if idle {
+                handlers::session::handle_lock_idle(interval, &config_dir)
+            } else {
+                handlers::session::handle_lock(&config_dir)
+            }
+        }
         Commands::Status => handlers::session::handle_status(&config_dir),
-        Commands::Export { file } => {
-            handlers::transfer::handle_export(&file, deck_name, &keyring, &config_dir)
+        Commands::Doctor => handlers::doctor::handle_doctor(&keyring, &config_dir),
+        Commands::Migrate => handlers::migrate::handle_migrate(&keyring, &config_dir),
+        Commands::Current => handlers::session::handle_current(deck_name, &config_dir),
+        Commands::Export {
+            file,
+            stdout,
+            exclude_totp,
+            exclude,
+            format,
+            insecure,
+            hand,
+        } => {
+            let file = if stdout {
+                None
+            } else {
+                file.filter(|f| f != "-")
+            };
+            let options = handlers::transfer::ExportOptions {
+                format,
+                insecure,
+                hand,
+                exclude_totp,
+                exclude,
+                assume_yes,
+            };
+            handlers::transfer::handle_export(
+                file.as_deref(),
+                &options,
+                deck_name,
+                &keyring,
+                &config_dir,
+            )
         }
-        Commands::Import { file, overwrite } => {
-            handlers::transfer::handle_import(&file, overwrite, deck_name, &keyring, &config_dir)
+        Commands::Import {
+            file,
+            overwrite,
+            strategy,
+        } => {
+            let strategy = strategy.unwrap_or(if overwrite {
+                domain::MergeStrategy::Overwrite
+            } else {
+                domain::MergeStrategy::Skip
+            });
+            handlers::transfer::handle_import(&file, strategy, deck_name, &keyring, &config_dir)
         }
         Commands::Totp { subcommand } => {
             handlers::totp::handle_totp(subcommand, deck_name, &keyring, &config_dir)
         }
         Commands::Provider { subcommand } => {
             let ctx = multi_deck_context::MultiDeckContext::load(deck_name, &keyring, &config_dir)?;
-            handlers::provider::handle_provider(&ctx.inner, &subcommand)
+            handlers::provider::handle_provider(&ctx.inner, &subcommand, assume_yes)
         }
         Commands::Generate {
             length,
@@ -161,6 +394,9 @@ fn main() -> Result<()> {
             no_digits,
             no_symbols,
             clip,
+            no_clear,
+            count,
+            check,
         } => handlers::password::handle_generate(
             length,
             memorable,
@@ -170,16 +406,24 @@ fn main() -> Result<()> {
             no_digits,
             no_symbols,
             clip,
+            no_clear,
+            count,
+            check,
+        ),
+        Commands::Deck { subcommand } => handlers::deck_management::handle_deck(
+            subcommand,
+            deck_name,
+            assume_yes,
+            &keyring,
+            &config_dir,
         ),
-        Commands::Deck { subcommand } => {
-            handlers::deck_management::handle_deck(subcommand, deck_name, &keyring, &config_dir)
-        }
         Commands::Ssh { subcommand } => {
-            handlers::ssh::handle_ssh(subcommand, deck_name, &keyring, &config_dir)
+            handlers::ssh::handle_ssh(subcommand, assume_yes, deck_name, &keyring, &config_dir)
         }
         Commands::Completion { shell } => handlers::completion::handle_completion(&shell),
         Commands::__CompleteHands { deck } => {
             handlers::completion::handle_complete_hands(deck.as_deref(), &config_dir)
         }
+        Commands::__ClearClipboard { after } => handlers::password::handle_clear_clipboard(after),
     }
 }
@@ -17,13 +17,14 @@ impl MultiDeckContext {
     ) -> Result<Self> {
         let registry = DeckRegistry::load(config_dir)?;
 
-        let deck_metadata = if let Some(name) = deck_name {
-            registry.get_deck(name)?
-        } else {
-            registry.get_active_deck()?
-        };
+        let deck_metadata = registry.resolve_deck(deck_name)?;
 
         let deck_path = &deck_metadata.path;
+        log::debug!(
+            "Using deck '{}' at {}",
+            deck_metadata.name,
+            deck_path.display()
+        );
         let inner = DeckContext::load(deck_path, &deck_metadata.name, keyring, config_dir)?;
 
         registry.touch_deck(&deck_metadata.name)?;
@@ -34,7 +35,7 @@ impl MultiDeckContext {
         })
     }
 
-    pub fn save(&self) -> Result<()> {
+    pub fn save(&mut self) -> Result<()> {
         self.inner.save()
     }
 }